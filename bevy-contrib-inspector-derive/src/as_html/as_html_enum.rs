@@ -1,73 +1,321 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote, ToTokens};
 
 use crate::as_html::DeriveDataEnum;
 
 pub fn to_tokens(data: DeriveDataEnum<'_>) -> TokenStream {
     let DeriveDataEnum { ident, variants } = data;
 
-    let html = html(variants.as_slice());
+    let html = html(&ident, variants.as_slice());
 
     let parse_arms = variants.iter().map(|variant| {
         let var_ident = &variant.ident;
         let var_ident_str = var_ident.to_string();
+        let default = default_variant(&ident, variant);
 
-        quote! { #var_ident_str => Ok(#ident::#var_ident) }
+        quote! { #var_ident_str => Ok(#default) }
     });
 
+    let variant_name_arms = variants.iter().map(|variant| {
+        let var_ident = &variant.ident;
+        let var_ident_str = var_ident.to_string();
+
+        quote! { #ident::#var_ident { .. } => #var_ident_str }
+    });
+
+    let field_update_arms = variants.iter().flat_map(|variant| {
+        let ident = &ident;
+        let var_ident = &variant.ident;
+        let var_ident_str = var_ident.to_string();
+
+        variant.fields.iter().enumerate().map(move |(i, field)| {
+            let ty = &field.ty;
+            let field_name = field_name(field, i);
+            let pattern = match &variant.fields {
+                syn::Fields::Named(_) => {
+                    let field_ident = &field.ident;
+                    quote! { #ident::#var_ident { #field_ident: field, .. } }
+                }
+                _ => {
+                    let skipped = (0..i).map(|_| quote! { _ });
+                    quote! { #ident::#var_ident(#(#skipped,)* field, ..) }
+                }
+            };
+
+            quote! {
+                (Some(#var_ident_str), Some(#field_name)) => match self {
+                    #pattern => <#ty as bevy_contrib_inspector::AsHtml>::update_with_options(field, value, &<#ty as bevy_contrib_inspector::AsHtml>::DEFAULT_OPTIONS).map_err(|e| format!("{:?}", e)),
+                    _ => Err(format!("'{}' is not the current variant '{}'", #var_ident_str, variant_name(self))),
+                }
+            }
+        })
+    });
+
+    // only the fields of the current variant are rendered
+    let live_arms = variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+        .map(|variant| {
+            let (pattern, bindings) = variant_pattern(&ident, variant);
+            let tys = variant.fields.iter().map(|field| &field.ty);
+
+            quote! {
+                #pattern => {
+                    #(html.push_str(&<#tys as bevy_contrib_inspector::as_html::AsHtml>::live_html(#bindings, &<#tys as bevy_contrib_inspector::as_html::AsHtml>::DEFAULT_OPTIONS));)*
+                }
+            }
+        });
+
+    let unit_enum = unit_enum(&ident, variants.as_slice());
+
+    let tys = variants
+        .iter()
+        .flat_map(|variant| variant.fields.iter().map(|field| &field.ty));
+
     quote! {
+    #[allow(warnings)]
+    const _: () = {
+    /// The name of the variant of `value`, as parsed by `AsHtml::parse`
+    fn variant_name(value: &#ident) -> &'static str {
+        match *value {
+            #(#variant_name_arms,)*
+        }
+    }
+
     impl bevy_contrib_inspector::as_html::AsHtml for #ident {
         type Err = String;
-        type Options = ();
-        const DEFAULT_OPTIONS: Self::Options = ();
+        type Options = bevy_contrib_inspector::as_html::EnumAttributes;
+        const DEFAULT_OPTIONS: Self::Options = bevy_contrib_inspector::as_html::EnumAttributes::DEFAULT;
 
         fn as_html(
             shared: bevy_contrib_inspector::as_html::SharedOptions<Self>,
-            (): Self::Options,
+            options: Self::Options,
             submit_fn: String,
         ) -> String {
             #html
         }
 
+        fn register_header_footer(
+            types: &mut std::collections::HashSet<std::any::TypeId>,
+            header: &mut String,
+            footer: &mut String,
+        ) {
+            #(<#tys as bevy_contrib_inspector::as_html::AsHtml>::register_header_footer(types, header, footer);)*
+        }
+
+        fn live_html(&self, _: &Self::Options) -> String {
+            let mut html = String::new();
+            match self {
+                #(#live_arms,)*
+                _ => {}
+            }
+            html
+        }
+
+        /// Parses the name of a variant, the fields of the variant get their default values.
         fn parse(value: &str) -> Result<Self, Self::Err> {
             match value {
                 #(#parse_arms,)*
                 _ => Err(value.to_string()),
             }
         }
+
+        fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+            let mut iter = value.splitn(2, ':');
+            let (path, value) = match (iter.next(), iter.next()) {
+                (Some(path), Some(value)) => (path, value),
+                _ => {
+                    *self = Self::parse(value)?;
+                    return Ok(());
+                }
+            };
+
+            let mut path_iter = path.splitn(2, '.');
+            match (path_iter.next(), path_iter.next()) {
+                #(#field_update_arms,)*
+                _ => Err(format!("unexpected field '{}'", path)),
+            }
+        }
+    }
+
+    #unit_enum
+    };
+    }
+}
+
+/// Implements `UnitEnum` if none of the variants have fields, so that the enum can be used as flags in a set
+fn unit_enum(ident: &syn::Ident, variants: &[&syn::Variant]) -> TokenStream {
+    if variants.is_empty() || variants.iter().any(|variant| !variant.fields.is_empty()) {
+        return TokenStream::new();
+    }
+
+    let var_idents = variants.iter().map(|variant| &variant.ident);
+    let var_labels = variants.iter().map(|variant| variant_label(variant));
+
+    quote! {
+        impl bevy_contrib_inspector::as_html::UnitEnum for #ident {
+            const VARIANTS: &'static [(&'static str, Self)] = &[#((#var_labels, #ident::#var_idents)),*];
+        }
+    }
+}
+
+/// `name` for named fields, `#0` for tuple fields
+fn field_name(field: &syn::Field, i: usize) -> String {
+    field
+        .ident
+        .as_ref()
+        .map_or_else(|| format!("#{}", i), |name| name.to_string())
+}
+
+/// The variant with all its fields set to `Default::default()`
+fn default_variant(ident: &syn::Ident, variant: &syn::Variant) -> TokenStream {
+    let var_ident = &variant.ident;
+    let defaults = variant.fields.iter().map(|field| {
+        let default = quote! { std::default::Default::default() };
+        match &field.ident {
+            Some(field_ident) => quote! { #field_ident: #default },
+            None => default,
+        }
+    });
+
+    match &variant.fields {
+        syn::Fields::Named(_) => quote! { #ident::#var_ident { #(#defaults),* } },
+        syn::Fields::Unnamed(_) => quote! { #ident::#var_ident(#(#defaults),*) },
+        syn::Fields::Unit => quote! { #ident::#var_ident },
     }
+}
+
+/// A pattern matching `variant` which binds its fields to `field_0`, `field_1`, ...
+fn variant_pattern(ident: &syn::Ident, variant: &syn::Variant) -> (TokenStream, Vec<syn::Ident>) {
+    let var_ident = &variant.ident;
+    let bindings: Vec<_> = (0..variant.fields.len())
+        .map(|i| format_ident!("field_{}", i))
+        .collect();
+    let pattern = match &variant.fields {
+        syn::Fields::Named(fields) => {
+            let field_idents = fields.named.iter().map(|field| &field.ident);
+            quote! { #ident::#var_ident { #(#field_idents: #bindings),* } }
+        }
+        _ => quote! { #ident::#var_ident(#(#bindings),*) },
+    };
+    (pattern, bindings)
+}
+
+/// The html of the fields of `variant`, with `values` as their values
+fn variant_fields_html(variant: &syn::Variant, values: &[TokenStream]) -> TokenStream {
+    let var_ident_str = variant.ident.to_string();
+
+    let fields = variant
+        .fields
+        .iter()
+        .enumerate()
+        .zip(values)
+        .map(|((i, field), value)| {
+            let ty = &field.ty;
+            let field_name = field_name(field, i);
+            let as_html = quote! { <#ty as bevy_contrib_inspector::as_html::AsHtml> };
+
+            quote! {
+                let shared_options = bevy_contrib_inspector::as_html::SharedOptions {
+                    label: std::borrow::Cow::Borrowed(#field_name),
+                    default: #value,
+                    readonly,
+                };
+                let field_submit_fn = format!("((value) => {}('{}.{}:'+value))", submit_fn, #var_ident_str, #field_name);
+                html.push_str(&#as_html::as_html(shared_options, #as_html::DEFAULT_OPTIONS, field_submit_fn));
+            }
+        });
+
+    quote! {{
+        let mut html = String::new();
+        #(#fields)*
+        html
+    }}
+}
+
+/// The label set using `#[inspectable(label = "...")]`, or the name of the variant
+fn variant_label(variant: &syn::Variant) -> TokenStream {
+    let mut label = variant.ident.to_string().into_token_stream();
+    for (left, right) in crate::attrs::inspectable_attributes(&variant.attrs) {
+        match left.to_string().as_str() {
+            "label" => label = right.into_token_stream(),
+            other => panic!("unexpected variant attribute '{}'", other),
+        }
     }
+    label
 }
 
-fn html(variants: &[&syn::Variant]) -> TokenStream {
+fn html(ident: &syn::Ident, variants: &[&syn::Variant]) -> TokenStream {
     let var_ident_strs = variants.iter().map(|v| v.ident.to_string());
+    let var_labels = variants.iter().map(|v| variant_label(v));
+
+    let data_variants: Vec<&syn::Variant> = variants
+        .iter()
+        .copied()
+        .filter(|variant| !variant.fields.is_empty())
+        .collect();
+
+    let current_fields_arms = data_variants.iter().map(|variant| {
+        let (pattern, bindings) = variant_pattern(ident, variant);
+        let values: Vec<_> = bindings.iter().map(|b| b.to_token_stream()).collect();
+        let html = variant_fields_html(variant, &values);
+
+        quote! { #pattern => #html }
+    });
+
+    let variant_containers = data_variants.iter().map(|variant| {
+        let var_ident_str = variant.ident.to_string();
+        let defaults: Vec<_> = variant
+            .fields
+            .iter()
+            .map(|_| quote! { std::default::Default::default() })
+            .collect();
+        let default_html = variant_fields_html(variant, &defaults);
+
+        quote! {
+            let is_current = current_variant == #var_ident_str;
+            html.push_str(&format!(
+                r#"<div class="enum-variant" data-variant="{variant}" {hidden}>{fields}</div><template data-variant="{variant}">{default_fields}</template>"#,
+                variant = #var_ident_str,
+                hidden = if is_current { "" } else { r#"style="display: none""# },
+                fields = if is_current { current_fields.as_str() } else { "" },
+                default_fields = #default_html,
+            ));
+        }
+    });
 
     quote! {
+    let current_variant = variant_name(&shared.default);
+    let disabled = shared.disabled();
+    let readonly = shared.readonly;
+    let label = shared.label;
+
+    let current_fields = match shared.default {
+        #(#current_fields_arms,)*
+        _ => String::new(),
+    };
+
     let mut html = String::new();
     html.push_str(&format!(
         r#"
+        <div class="enum">
         <div class="row">
             <label class="cell text-right">{}:</label>
             <div class="cell">"#,
-        shared.label,
+        label,
     ));
 
-    for field in &[#(#var_ident_strs),*] {
-        html.push_str(&format!(
-            r#"
-            <label>
-                <input type="radio" value="{value}" name="{name}" {checked} oninput="{}(this.value)"/>
-                {value}
-            </label>
-            "#,
-            submit_fn,
-            value = field,
-            name = shared.label,
-            checked=if format!("{:?}", shared.default) == *field { "checked" } else {""}
-        ));
-    }
-
+    html.push_str(&bevy_contrib_inspector::as_html::enum_variant_selector(
+        &label,
+        &[#((#var_ident_strs, #var_labels)),*],
+        current_variant,
+        &options,
+        disabled,
+        &submit_fn,
+    ));
     html.push_str(r#"</div></div>"#);
+    #(#variant_containers)*
+    html.push_str(r#"</div>"#);
     html
     }
 }
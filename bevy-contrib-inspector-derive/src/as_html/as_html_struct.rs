@@ -12,12 +12,16 @@ pub fn to_tokens(data: DeriveDataStruct<'_>) -> TokenStream {
         let ty = &field.ty;
         let field_name = field_name(field, i);
 
-        let accessor = field.ident.as_ref().map_or_else(
-            || syn::Index::from(i).to_token_stream(),
-            |name| quote!{#name}
-        );
+        let accessor = accessor(field, i);
 
-        quote! { #field_name => self.#accessor = <#ty as bevy_contrib_inspector::AsHtml>::parse(value).map_err(|e| format!("{:?}", e))? }
+        // the fields are rendered with their default options, so they're updated with them as well
+        quote! { #field_name => <#ty as bevy_contrib_inspector::AsHtml>::update_with_options(&mut self.#accessor, value, &<#ty as bevy_contrib_inspector::AsHtml>::DEFAULT_OPTIONS).map_err(|e| format!("{:?}", e))? }
+    });
+    let live_fields = fields.iter().enumerate().map(|(i, field)| {
+        let ty = &field.ty;
+        let accessor = accessor(field, i);
+
+        quote! { html.push_str(&<#ty as bevy_contrib_inspector::as_html::AsHtml>::live_html(&self.#accessor, &<#ty as bevy_contrib_inspector::as_html::AsHtml>::DEFAULT_OPTIONS)); }
     });
     let tys = fields.iter().map(|field| &field.ty);
 
@@ -44,6 +48,12 @@ pub fn to_tokens(data: DeriveDataStruct<'_>) -> TokenStream {
             #(<#tys as bevy_contrib_inspector::as_html::AsHtml>::register_header_footer(types, header, footer);)*
         }
 
+        fn live_html(&self, (): &Self::Options) -> String {
+            let mut html = String::new();
+            #(#live_fields)*
+            html
+        }
+
         fn parse(_: &str) -> Result<Self, Self::Err> {
             unreachable!("AsHtml::update will be used instead")
         }
@@ -71,11 +81,20 @@ fn field_name(field: &syn::Field, i: usize) -> String {
         .map_or_else(|| format!("#{}", i), |name| name.to_string())
 }
 
+fn accessor(field: &syn::Field, i: usize) -> TokenStream {
+    field.ident.as_ref().map_or_else(
+        || syn::Index::from(i).to_token_stream(),
+        |name| quote! {#name},
+    )
+}
+
 fn html(struct_name: &syn::Ident, fields: &[&syn::Field]) -> TokenStream {
     let fields = fields.into_iter().enumerate().map(|(i, field)| {
         let ty = &field.ty;
         let field_name = field_name(field, i);
 
+        let accessor = accessor(field, i);
+
         let as_html = quote! { <#ty as bevy_contrib_inspector::as_html::AsHtml> };
 
         let submit_fn = quote! {
@@ -85,7 +104,8 @@ fn html(struct_name: &syn::Ident, fields: &[&syn::Field]) -> TokenStream {
         quote! {
             let shared_options = bevy_contrib_inspector::as_html::SharedOptions {
                 label: std::borrow::Cow::Borrowed(#field_name),
-                default: Default::default(),
+                default: value.#accessor,
+                readonly: shared.readonly,
             };
             let octave_html = #as_html::as_html(
                 shared_options,
@@ -99,6 +119,7 @@ fn html(struct_name: &syn::Ident, fields: &[&syn::Field]) -> TokenStream {
     let struct_name_str = struct_name.to_string();
 
     quote! {
+        let value = shared.default;
         let mut html = format!("<b>{}</b>", #struct_name_str);
         #(#fields)*
         html.push_str("<br />");
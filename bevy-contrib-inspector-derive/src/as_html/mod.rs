@@ -16,13 +16,7 @@ pub struct DeriveDataStruct<'a> {
 pub fn expand(input: syn::DeriveInput) -> TokenStream {
     match input.data {
         syn::Data::Enum(data) => {
-            let variants = data
-                .variants
-                .iter()
-                .inspect(|variant| {
-                    assert!(variant.fields.is_empty(), "only unit enums are supported");
-                })
-                .collect();
+            let variants = data.variants.iter().collect();
 
             let data = DeriveDataEnum {
                 ident: input.ident,
@@ -3,6 +3,9 @@ fn parse_inspectable_attributes(
 ) -> syn::Result<impl Iterator<Item = (syn::Ident, syn::Expr)>> {
     let parse_attribute = |input: syn::parse::ParseStream| {
         let ident: syn::Ident = input.parse()?;
+        if !input.peek(syn::Token![=]) {
+            return Ok((ident, syn::parse_quote!(true)));
+        }
         let _eq_token: syn::Token![=] = input.parse()?;
         let expr: syn::Expr = input.parse()?;
         Ok((ident, expr))
@@ -14,6 +17,7 @@ fn parse_inspectable_attributes(
 }
 
 /// extracts [(min, 8), (field, vec2(1.0, 1.0))] from `#[inspectable(min = 8, field = vec2(1.0, 1.0))]`,
+/// a lone `#[inspectable(flag)]` is short for `#[inspectable(flag = true)]`
 pub fn inspectable_attributes(
     attrs: &[syn::Attribute],
 ) -> impl Iterator<Item = (syn::Ident, syn::Expr)> + '_ {
@@ -6,6 +6,8 @@ struct Field<'a> {
     ident: &'a syn::Ident,
     ty: &'a syn::Type,
     attrs: &'a Vec<syn::Attribute>,
+    /// set using `#[inspectable(readonly)]`
+    readonly: bool,
 }
 
 pub struct DeriveData<'a> {
@@ -30,10 +32,14 @@ impl<'a> DeriveData<'a> {
             .iter()
             .map(|field| {
                 let ident = field.ident.as_ref().expect("field should be named");
+                let readonly = crate::attrs::inspectable_attributes(&field.attrs)
+                    .any(|(name, _)| name == "readonly");
+                check_scale(ident, &field.attrs);
                 Field {
                     ident,
                     ty: &field.ty,
                     attrs: &field.attrs,
+                    readonly,
                 }
             })
             .collect();
@@ -67,21 +73,47 @@ impl<'a> DeriveData<'a> {
             let ident_str = ident.to_string();
             let ty = &field.ty;
 
+            if field.readonly {
+                return quote! {
+                    #ident_str => Err(format!("field '{}' is readonly", #ident_str))
+                };
+            }
+
+            let options = field_options(field);
+
             quote! {
-                #ident_str => if let Err(e) = <#ty as bevy_contrib_inspector::as_html::AsHtml>::update(&mut self.#ident, &value) {
-                    eprintln!("failed to parse '{}': {:?}", #ident_str, e);
-                }
+                #ident_str => <#ty as bevy_contrib_inspector::as_html::AsHtml>::update_with_options(&mut self.#ident, &value, &#options)
+                    .map_err(|e| format!("failed to parse '{}': {:?}", #ident_str, e))
             }
         });
 
         let html = html(&fields);
 
+        let watched_fields = fields.iter().map(|field| {
+            let ident = field.ident;
+            let ident_str = ident.to_string();
+            let ty = field.ty;
+
+            if field.readonly {
+                let field_html = field_html(field, quote! { std::clone::Clone::clone(&self.#ident) });
+                quote! { watched.push((#ident_str, #field_html)); }
+            } else {
+                let options = field_options(field);
+                quote! {
+                    if bevy_contrib_inspector::as_html::has_live_parts::<#ty>() {
+                        let html = <#ty as bevy_contrib_inspector::as_html::AsHtml>::live_html(&self.#ident, &#options);
+                        watched.push((#ident_str, html));
+                    }
+                }
+            }
+        });
+
         quote! {
             impl bevy_contrib_inspector::Inspectable for #ident {
-                fn update(&mut self, field: &str, value: &str) {
+                fn update(&mut self, field: &str, value: &str) -> Result<(), String> {
                     match field {
                         #(#match_arms,)*
-                        _ => eprintln!("unexpected field '{}'", field),
+                        _ => Err(format!("unexpected field '{}'", field)),
                     }
                 }
 
@@ -89,6 +121,12 @@ impl<'a> DeriveData<'a> {
                     #html
                 }
 
+                fn watched(&self) -> Vec<(&'static str, String)> {
+                    let mut watched = Vec::new();
+                    #(#watched_fields)*
+                    watched
+                }
+
                 fn options() -> bevy_contrib_inspector::InspectableOptions {
                     #inspectable_options
                 }
@@ -97,35 +135,143 @@ impl<'a> DeriveData<'a> {
     }
 }
 
+/// the `AsHtml::Options` of a field, with the attributes of the field applied
+fn field_options(field: &Field<'_>) -> TokenStream {
+    let ty = &field.ty;
+    let option_fields = crate::attrs::inspectable_attributes(&field.attrs)
+        .filter(|(left, _)| left != "readonly")
+        .map(|(left, right)| quote! { options.#left = #right; });
+
+    quote! {{
+        let mut options = <#ty as bevy_contrib_inspector::as_html::AsHtml>::DEFAULT_OPTIONS;
+        #(#option_fields)*
+        options
+    }}
+}
+
+/// rejects a `#[inspectable(scale = "..")]` that can't be used, so that it isn't silently
+/// replaced by a linear scale when the field is rendered.
+/// `min` and `max` are only checked if they are number literals.
+fn check_scale(ident: &syn::Ident, attrs: &[syn::Attribute]) {
+    let attributes: Vec<_> = crate::attrs::inspectable_attributes(attrs).collect();
+    let attribute = |name: &str| {
+        attributes
+            .iter()
+            .find(|(left, _)| left == name)
+            .map(|(_, right)| right)
+    };
+
+    let scale = match attribute("scale") {
+        None => return,
+        Some(syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(scale),
+            ..
+        })) => scale.value(),
+        Some(_) => panic!("the scale of '{}' should be a string literal", ident),
+    };
+    let exponent = scale
+        .strip_prefix("pow(")
+        .and_then(|scale| scale.strip_suffix(')'))
+        .map(|exponent| exponent.trim().parse::<f64>());
+    match (scale.as_str(), exponent) {
+        ("linear", _) => return,
+        ("log", _) => {}
+        (_, Some(Ok(exponent))) if exponent > 0.0 => {}
+        _ => panic!(
+            "invalid scale '{}' of '{}': expected \"linear\", \"log\" or \"pow(n)\" with n > 0",
+            scale, ident
+        ),
+    }
+
+    let (min, max) = match (attribute("min"), attribute("max")) {
+        (Some(min), Some(max)) => (number_literal(min), number_literal(max)),
+        _ => panic!(
+            "invalid scale '{}' of '{}': a non-linear scale needs a min and max",
+            scale, ident
+        ),
+    };
+    if let ("log", Some(min), Some(max)) = (scale.as_str(), min, max) {
+        if !(min > 0.0 && max > min) {
+            panic!(
+                "invalid scale '{}' of '{}': a logarithmic scale needs 0 < min < max",
+                scale, ident
+            );
+        }
+    }
+}
+
+/// the value of a possibly negated integer or float literal
+fn number_literal(expr: &syn::Expr) -> Option<f64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse().ok(),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Float(float),
+            ..
+        }) => float.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => number_literal(expr).map(|value: f64| -value),
+        syn::Expr::Paren(syn::ExprParen { expr, .. }) => number_literal(expr),
+        _ => None,
+    }
+}
+
+/// renders a field with `value` as its current value
+fn field_html(field: &Field<'_>, value: TokenStream) -> TokenStream {
+    let ty = &field.ty;
+    let ident_str = field.ident.to_string();
+    let readonly = field.readonly;
+
+    let as_html = quote! { <#ty as bevy_contrib_inspector::as_html::AsHtml> };
+    let options = field_options(field);
+
+    quote! {{
+        let shared = bevy_contrib_inspector::as_html::SharedOptions {
+            label: std::borrow::Cow::Borrowed(#ident_str),
+            default: #value,
+            readonly: #readonly,
+        };
+
+        let options = #options;
+
+        let submit_fn = concat!("(value => handleChange('", #ident_str, "', value))").to_string();
+
+        #as_html::as_html(shared, options, submit_fn)
+    }}
+}
+
 fn html<'a>(fields: &[Field<'a>]) -> TokenStream {
     let fields_as_html = fields.iter().map(|field| {
-        let ty = &field.ty;
-        let attrs = &field.attrs;
         let ident = &field.ident;
         let ident_str = ident.to_string();
+        let field_html = field_html(field, quote! { defaults.#ident });
 
-        let as_html = quote! { <#ty as bevy_contrib_inspector::as_html::AsHtml> };
-        let option_fields = crate::attrs::inspectable_attributes(&attrs)
-            .map(|(left, right)| quote! { options.#left = #right; });
+        let ty = field.ty;
 
-        quote! {
-            let shared = bevy_contrib_inspector::as_html::SharedOptions {
-                label: std::borrow::Cow::Borrowed(#ident_str),
-                default: defaults.#ident,
-            };
-
-            let mut options = #as_html::DEFAULT_OPTIONS;
-            #(#option_fields)*
-
-            let submit_fn = concat!("(value => handleChange('", #ident_str, "', value))").to_string();
-
-            inputs.push_str(&#as_html::as_html(shared, options, submit_fn));
+        if field.readonly {
+            // replaced with the current value by `startWatching`, see ../static/script.js
+            quote! {
+                inputs.push_str(&format!(r#"<div class="watch" data-watch="{}">{}</div>"#, #ident_str, #field_html));
+                watching = true;
+            }
+        } else {
+            // only the parts marked with `data-live` are replaced
+            quote! {
+                if bevy_contrib_inspector::as_html::has_live_parts::<#ty>() {
+                    inputs.push_str(&format!(r#"<div data-watch="{}" data-watch-live>{}</div>"#, #ident_str, #field_html));
+                    watching = true;
+                } else {
+                    inputs.push_str(&#field_html);
+                }
+            }
         }
     });
 
-    let css = include_str!("../static/style.css");
-    let js = include_str!("../static/script.js");
-
     let tys = fields.iter().map(|field| &field.ty);
 
     quote! {
@@ -136,38 +282,16 @@ fn html<'a>(fields: &[Field<'a>]) -> TokenStream {
         #(<#tys as bevy_contrib_inspector::as_html::AsHtml>::register_header_footer(&mut field_types, &mut header, &mut footer);)*
 
         let mut inputs = String::new();
+        #[allow(unused_mut)]
+        let mut watching = false;
         let defaults = <Self as std::default::Default>::default();
         #(#fields_as_html)*
 
+        if watching {
+            let watch_interval = <Self as bevy_contrib_inspector::Inspectable>::options().watch_interval;
+            inputs.push_str(&format!("<script>startWatching({})</script>", watch_interval));
+        }
 
-        format!(
-r#"
-<!DOCTYPE html>
-<html>
-<head>
-<meta charset="utf-8" />
-{header}
-<style>{css}</style>
-</head>
-<body>
-    <script>
-    const handleChangeThrottle = {inspectable_throttle};
-    {js}
-    </script>
-
-    <div id="inputs">
-    {inputs}
-    </div>
-
-    {footer}
-</body>
-</html>"#,
-            header=header,
-            footer=footer,
-            css=#css,
-            js=#js,
-            inputs=inputs,
-            inspectable_throttle=10, // used in ../static/script.js
-        )
+        bevy_contrib_inspector::html_page(&header, &inputs, &footer)
     }
 }
@@ -9,7 +9,7 @@ pub fn inspectable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     inspectable::DeriveData::expand(input).into()
 }
 
-#[proc_macro_derive(AsHtml)]
+#[proc_macro_derive(AsHtml, attributes(inspectable))]
 pub fn as_html(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
 
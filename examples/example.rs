@@ -15,7 +15,6 @@ struct Data {
     text: String,
     show_square: bool,
     text_color: TextColor,
-    color: Color,
     #[inspectable(min = Vec2::new(-200., -200.), max = Vec2::new(200., 200.))]
     position: Vec2,
 }
@@ -26,7 +25,6 @@ impl Default for Data {
             text: "Hello World!".to_string(),
             show_square: true,
             text_color: TextColor::White,
-            color: Color::BLUE,
             position: Vec2::default(),
         }
     }
@@ -54,15 +52,9 @@ fn text_update_system(data: Res<Data>, mut query: Query<&mut Text>) {
     }
 }
 
-fn shape_update_system(
-    data: ChangedRes<Data>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    mut query: Query<(&Handle<ColorMaterial>, &mut Transform)>,
-) {
-    for (color, mut transfrom) in query.iter_mut() {
-        let material = materials.get_mut(color).unwrap();
-        material.color = data.color;
-
+// the color of the square can be edited at /assets
+fn shape_update_system(data: ChangedRes<Data>, mut query: Query<&mut Transform, With<Sprite>>) {
+    for mut transfrom in query.iter_mut() {
         if !data.show_square {
             transfrom.translation.x = 1000000.0;
         } else {
@@ -0,0 +1,129 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use bevy::asset::HandleId;
+use bevy::prelude::*;
+use tiny_http::Method;
+
+use crate::handles;
+use crate::inspector_server::AppRequest;
+use crate::registry::{AssetRegistration, InspectableRegistry};
+
+/// In urls and updates, handles are addressed by the hex-encoded hash of their id.
+pub(crate) fn handle_key(id: HandleId) -> String {
+    let mut hasher = DefaultHasher::new();
+    id.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+/// The path of the asset if it was loaded using the `AssetServer`, otherwise its key.
+pub(crate) fn handle_label(resources: &Resources, id: HandleId) -> String {
+    resources
+        .get::<AssetServer>()
+        .and_then(|asset_server| {
+            let path = asset_server.get_handle_path(id)?;
+            Some(path.path().display().to_string())
+        })
+        .unwrap_or_else(|| handle_key(id))
+}
+
+/// Handles
+/// - `GET /assets`: list of the registered asset types
+/// - `GET /assets/<type>`: all assets of a type
+/// - `PUT /assets/<type>` with `<handle>:<value>`: update an asset
+pub(crate) fn handle_request(_: &mut World, resources: &mut Resources, request: AppRequest) {
+    let registry = match resources.get::<InspectableRegistry>() {
+        Some(registry) => registry,
+        None => return request.respond_status(404),
+    };
+
+    let index = request.path()["/assets".len()..].trim_matches('/');
+    let asset = match index.parse::<usize>() {
+        _ if index.is_empty() => None,
+        Ok(index) if index < registry.assets.len() => Some(&registry.assets[index]),
+        _ => return request.respond_status(404),
+    };
+
+    let method = request.method().clone();
+    match (method, asset) {
+        (Method::Get, None) => request.respond_html(asset_list(resources, &registry)),
+        (Method::Get, Some(asset)) => request.respond_html(asset_page(resources, asset)),
+        (Method::Put, Some(asset)) => {
+            let mut iter = request.body.splitn(2, ':');
+            let id = iter.next().and_then(|key| {
+                let handles = (asset.handles)(resources)?;
+                handles.into_iter().find(|id| handle_key(*id) == key)
+            });
+            let update = match (id, iter.next()) {
+                (Some(id), Some(value)) => {
+                    handles::with_resources(resources, || (asset.update)(resources, id, value))
+                }
+                _ => Err(format!("unexpected asset update '{}'", request.body)),
+            };
+            match update {
+                Ok(()) => request.respond_status(200),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    request.respond_error(e);
+                }
+            }
+        }
+        _ => request.respond_status(404),
+    }
+}
+
+fn asset_list(resources: &Resources, registry: &InspectableRegistry) -> String {
+    let mut inputs = String::from("<b>Assets</b>");
+    for (i, asset) in registry.assets.iter().enumerate() {
+        let handles = match (asset.handles)(resources) {
+            Some(handles) => handles,
+            None => continue,
+        };
+
+        inputs.push_str(&format!(
+            r#"
+            <div class="row">
+                <a class="cell text-right" href="/assets/{}">{}</a>
+                <span class="cell">{} assets</span>
+            </div>
+            "#,
+            i,
+            asset.name,
+            handles.len(),
+        ));
+    }
+
+    crate::html_page("", &inputs, "")
+}
+
+fn asset_page(resources: &Resources, asset: &AssetRegistration) -> String {
+    let mut header = String::new();
+    let mut footer = String::new();
+    (asset.register_header_footer)(&mut HashSet::new(), &mut header, &mut footer);
+
+    let mut handles: Vec<(String, HandleId)> = (asset.handles)(resources)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|id| (handle_label(resources, id), id))
+        .collect();
+    handles.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut inputs = String::new();
+    for (label, id) in handles {
+        let submit_fn = format!("(value => handleChange('{}', value))", handle_key(id));
+        if let Some(html) = (asset.html)(resources, id, submit_fn) {
+            inputs.push_str(&format!(
+                r#"
+            <div class="row">
+                <code class="cell text-right">{}</code>
+            </div>
+            "#,
+                crate::html_impls::escape_html(&label)
+            ));
+            inputs.push_str(&html);
+        }
+    }
+
+    crate::html_page(&header, &inputs, &footer)
+}
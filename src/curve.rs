@@ -0,0 +1,261 @@
+use std::cmp::Ordering;
+
+use crate::as_html::{AsHtml, SharedOptions};
+
+/// How a [`Curve`] gets from one keyframe to the next
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interpolation {
+    /// Keeps the value of the keyframe until the next one
+    Constant,
+    Linear,
+    /// Eases in and out of the keyframes
+    Smooth,
+}
+
+impl Interpolation {
+    fn name(self) -> &'static str {
+        match self {
+            Interpolation::Constant => "constant",
+            Interpolation::Linear => "linear",
+            Interpolation::Smooth => "smooth",
+        }
+    }
+
+    fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "constant" => Ok(Interpolation::Constant),
+            "linear" => Ok(Interpolation::Linear),
+            "smooth" => Ok(Interpolation::Smooth),
+            other => Err(format!("unknown interpolation '{}'", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keyframe {
+    pub time: f32,
+    pub value: f32,
+    /// The interpolation towards the next keyframe
+    pub interpolation: Interpolation,
+}
+
+/// A 1D curve defined by keyframes, which can be edited by dragging them in the inspector.
+///
+/// ```rust
+/// use bevy_contrib_inspector::{Curve, Interpolation};
+///
+/// let difficulty = Curve::new(vec![(0.0, 1.0, Interpolation::Smooth), (60.0, 3.0, Interpolation::Linear)]);
+/// let current_difficulty = difficulty.sample(30.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Curve {
+    /// sorted by time
+    keyframes: Vec<Keyframe>,
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Curve::new(vec![
+            (0.0, 0.0, Interpolation::Linear),
+            (1.0, 1.0, Interpolation::Linear),
+        ])
+    }
+}
+
+impl Curve {
+    /// Creates a curve from `(time, value, interpolation)` keyframes, which don't have to be sorted.
+    pub fn new(keyframes: Vec<(f32, f32, Interpolation)>) -> Self {
+        let mut curve = Curve {
+            keyframes: keyframes
+                .into_iter()
+                .map(|(time, value, interpolation)| Keyframe {
+                    time,
+                    value,
+                    interpolation,
+                })
+                .collect(),
+        };
+        curve.sort();
+        curve
+    }
+
+    /// The keyframes, sorted by their time
+    pub fn keyframes(&self) -> &[Keyframe] {
+        &self.keyframes
+    }
+
+    pub fn add_keyframe(&mut self, time: f32, value: f32, interpolation: Interpolation) {
+        self.keyframes.push(Keyframe {
+            time,
+            value,
+            interpolation,
+        });
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        self.keyframes
+            .sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap_or(Ordering::Equal));
+    }
+
+    /// The value at `time`, interpolated between the surrounding keyframes.
+    ///
+    /// Times before the first or after the last keyframe get the value of that keyframe.
+    /// An empty curve is zero everywhere.
+    pub fn sample(&self, time: f32) -> f32 {
+        let next = self.keyframes.iter().position(|key| key.time > time);
+        let (from, to) = match next {
+            Some(0) => return self.keyframes[0].value,
+            Some(i) => (&self.keyframes[i - 1], &self.keyframes[i]),
+            None => return self.keyframes.last().map_or(0.0, |key| key.value),
+        };
+
+        let t = (time - from.time) / (to.time - from.time);
+        let t = match from.interpolation {
+            Interpolation::Constant => 0.0,
+            Interpolation::Linear => t,
+            Interpolation::Smooth => t * t * (3.0 - 2.0 * t),
+        };
+        from.value + (to.value - from.value) * t
+    }
+
+    /// `time,value,interpolation` for every keyframe separated by `;`
+    fn serialize(&self) -> String {
+        self.keyframes
+            .iter()
+            .map(|key| format!("{},{},{}", key.time, key.value, key.interpolation.name()))
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+}
+
+/// The range shown in the curve editor. Keyframes can't be dragged outside of it.
+#[derive(Clone)]
+pub struct CurveAttributes {
+    pub min_time: f32,
+    pub max_time: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+/// The curve is submitted as a whole, see `static/curve_ashtml.js`.
+impl AsHtml for Curve {
+    type Err = String;
+    type Options = CurveAttributes;
+    const DEFAULT_OPTIONS: Self::Options = CurveAttributes {
+        min_time: 0.0,
+        max_time: 1.0,
+        min: 0.0,
+        max: 1.0,
+    };
+
+    fn footer() -> &'static str {
+        concat!(
+            "<script>",
+            include_str!("../static/curve_ashtml.js"),
+            "</script>"
+        )
+    }
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        format!(
+            r#"
+            <div class="row">
+                <label class="cell text-right" style="vertical-align: middle;">{label}:</label>
+                <div class="cell curve" data-curve="{curve}" data-curve-range="{min_time},{max_time},{min},{max}" {readonly}>
+                    <canvas width="240" height="120" style="width: 15rem; height: 7.5rem; border: 1px solid #000"></canvas>
+                    <div class="curve-keyframe">
+                        <label class="vector-component">time <input type="number" name="time" step="any" {disabled}></label>
+                        <label class="vector-component">value <input type="number" name="value" step="any" {disabled}></label>
+                        <select {disabled}>
+                            <option value="constant">Constant</option>
+                            <option value="linear">Linear</option>
+                            <option value="smooth">Smooth</option>
+                        </select>
+                        <button {disabled}>Remove</button>
+                    </div>
+                    <input type="hidden" oninput="{submit_fn}(this.value)">
+                </div>
+            </div>
+            "#,
+            label = shared.label,
+            curve = shared.default.serialize(),
+            min_time = options.min_time,
+            max_time = options.max_time,
+            min = options.min,
+            max = options.max,
+            readonly = if shared.readonly { "data-readonly" } else { "" },
+            disabled = shared.disabled(),
+            submit_fn = submit_fn,
+        )
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let keyframes = value
+            .split(';')
+            .filter(|key| !key.is_empty())
+            .map(|key| {
+                let mut iter = key.splitn(3, ',');
+                match (iter.next(), iter.next(), iter.next()) {
+                    (Some(time), Some(value), Some(interpolation)) => {
+                        let parse = |number: &str| {
+                            number
+                                .parse::<f32>()
+                                .map_err(|_| format!("invalid number '{}'", number))
+                        };
+                        Ok((
+                            parse(time)?,
+                            parse(value)?,
+                            Interpolation::from_name(interpolation)?,
+                        ))
+                    }
+                    _ => Err(format!(
+                        "expected 'time,value,interpolation', got '{}'",
+                        key
+                    )),
+                }
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Curve::new(keyframes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyframes_are_parsed_and_sorted() {
+        let curve = Curve::parse("1,2,linear;0,0.5,smooth;").unwrap();
+        assert_eq!(
+            curve,
+            Curve::new(vec![
+                (0.0, 0.5, Interpolation::Smooth),
+                (1.0, 2.0, Interpolation::Linear),
+            ])
+        );
+        assert_eq!(Curve::parse(&curve.serialize()), Ok(curve));
+        assert!(Curve::parse("").unwrap().keyframes().is_empty());
+
+        assert!(Curve::parse("0,1").is_err());
+        assert!(Curve::parse("0,x,linear").is_err());
+        assert!(Curve::parse("0,1,cubic").is_err());
+    }
+
+    #[test]
+    fn curves_are_sampled_between_keyframes() {
+        let curve = Curve::new(vec![
+            (0.0, 0.0, Interpolation::Linear),
+            (1.0, 2.0, Interpolation::Smooth),
+            (2.0, 4.0, Interpolation::Constant),
+            (3.0, 8.0, Interpolation::Linear),
+        ]);
+        assert_eq!(curve.sample(-1.0), 0.0);
+        assert_eq!(curve.sample(0.5), 1.0);
+        assert_eq!(curve.sample(1.25), 2.0 + 2.0 * 0.15625);
+        assert_eq!(curve.sample(2.5), 4.0);
+        assert_eq!(curve.sample(10.0), 8.0);
+        assert_eq!(Curve::new(Vec::new()).sample(1.0), 0.0);
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::{HashMap, VecDeque};
+
+use bevy::diagnostic::{DiagnosticId, Diagnostics};
+use bevy::prelude::*;
+use bevy::utils::Instant;
+
+use crate::panels::{Panel, Panels};
+
+/// How many measurements are shown in the sparkline of a diagnostic
+const HISTORY_LENGTH: usize = 120;
+const SPARKLINE_WIDTH: usize = 120;
+const SPARKLINE_HEIGHT: usize = 20;
+
+/// The recent measurements of a diagnostic
+#[derive(Default)]
+struct History {
+    /// When the last recorded measurement was taken, so that it isn't recorded again
+    /// in frames in which the diagnostic isn't measured.
+    last_measurement: Option<Instant>,
+    values: VecDeque<f64>,
+}
+
+/// The recent measurements of every diagnostic.
+///
+/// Bevy's `Diagnostic` keeps a history as well, but doesn't expose it.
+#[derive(Default)]
+struct DiagnosticsHistory(HashMap<DiagnosticId, History>);
+
+fn record_history(diagnostics: Res<Diagnostics>, mut history: ResMut<DiagnosticsHistory>) {
+    for diagnostic in diagnostics.iter() {
+        let measurement = match diagnostics.get_measurement(diagnostic.id) {
+            Some(measurement) => measurement,
+            None => continue,
+        };
+
+        let recorded = history.0.entry(diagnostic.id).or_default();
+        if recorded.last_measurement == Some(measurement.time) {
+            continue;
+        }
+        recorded.last_measurement = Some(measurement.time);
+
+        if recorded.values.len() == HISTORY_LENGTH {
+            recorded.values.pop_front();
+        }
+        recorded.values.push_back(measurement.value);
+    }
+}
+
+/// Adds a panel showing the current and average value of every diagnostic, as well as a sparkline of its history.
+/// The panel is empty unless a plugin like the `FrameTimeDiagnosticsPlugin` adds diagnostics.
+pub(crate) fn add_diagnostics_panel(app: &mut AppBuilder) {
+    if app.resources().contains::<DiagnosticsHistory>() {
+        return;
+    }
+    if !app.resources().contains::<Diagnostics>() {
+        app.add_resource(Diagnostics::default());
+    }
+
+    app.add_resource(DiagnosticsHistory::default())
+        .add_system(record_history.system());
+
+    Panels::add_panel(
+        app,
+        Panel {
+            name: "diagnostics",
+            refresh_interval: Some(500),
+            html: panel_html,
+            update: |_, action| eprintln!("unexpected diagnostics action '{}'", action),
+        },
+    );
+}
+
+fn panel_html(resources: &Resources) -> String {
+    let (diagnostics, history) = match (
+        resources.get::<Diagnostics>(),
+        resources.get::<DiagnosticsHistory>(),
+    ) {
+        (Some(diagnostics), Some(history)) => (diagnostics, history),
+        _ => return String::new(),
+    };
+
+    let mut diagnostics: Vec<_> = diagnostics.iter().collect();
+    if diagnostics.is_empty() {
+        return String::new();
+    }
+    diagnostics.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let format_value =
+        |value: Option<f64>| value.map_or_else(|| "-".to_string(), |v| format!("{:.2}", v));
+
+    let mut html = String::from("<b>Diagnostics</b>");
+    for diagnostic in diagnostics {
+        let graph = history
+            .0
+            .get(&diagnostic.id)
+            .map(|history| sparkline(&history.values))
+            .unwrap_or_default();
+
+        html.push_str(&format!(
+            r#"
+            <div class="row">
+                <span class="cell text-right">{name}:</span>
+                <span class="cell">
+                    <code>{value}</code> (avg <code>{average}</code>)
+                    {graph}
+                </span>
+            </div>
+            "#,
+            name = diagnostic.name,
+            value = format_value(diagnostic.value()),
+            average = format_value(diagnostic.average()),
+            graph = graph,
+        ));
+    }
+    html
+}
+
+/// An svg line chart of `values`, scaled to fit between their minimum and maximum.
+fn sparkline(values: &VecDeque<f64>) -> String {
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = if max > min { max - min } else { 1.0 };
+
+    let step = SPARKLINE_WIDTH as f64 / (HISTORY_LENGTH - 1) as f64;
+    let points: Vec<String> = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let x = i as f64 * step;
+            let y = SPARKLINE_HEIGHT as f64 * (1.0 - (value - min) / range);
+            format!("{:.1},{:.1}", x, y)
+        })
+        .collect();
+
+    format!(
+        r#"<svg width="{width}" height="{height}" style="vertical-align: middle"><polyline points="{points}" fill="none" stroke="currentColor" /></svg>"#,
+        width = SPARKLINE_WIDTH,
+        height = SPARKLINE_HEIGHT,
+        points = points.join(" "),
+    )
+}
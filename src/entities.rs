@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use tiny_http::Method;
+
+use crate::handles;
+use crate::inspector_server::AppRequest;
+use crate::registry::InspectableRegistry;
+
+/// Handles
+/// - `GET /entities`: list of all entities
+/// - `GET /entities/<entity>`: the registered components of an entity
+/// - `PUT /entities/<entity>` with `<component>:<value>`: update a component
+/// - `POST /entities/<entity>/<component>`: insert the default value of a component
+/// - `DELETE /entities/<entity>/<component>`: remove a component
+pub(crate) fn handle_request(world: &mut World, resources: &mut Resources, request: AppRequest) {
+    let registry = match resources.get::<InspectableRegistry>() {
+        Some(registry) => registry,
+        None => return request.respond_status(404),
+    };
+
+    let mut segments = request.path()["/entities".len()..]
+        .split('/')
+        .filter(|segment| !segment.is_empty());
+    let entity = match segments.next().map(str::parse) {
+        None => None,
+        Some(Ok(bits)) => Some(Entity::from_bits(bits)),
+        Some(Err(_)) => return request.respond_status(404),
+    };
+    let component = match segments.next().map(str::parse::<usize>) {
+        None => None,
+        Some(Ok(index)) => match registry.components.get(index) {
+            Some(component) => Some(component),
+            None => return request.respond_status(404),
+        },
+        Some(Err(_)) => return request.respond_status(404),
+    };
+
+    let method = request.method().clone();
+    match (method, entity, component) {
+        (Method::Get, None, None) => request.respond_html(entity_list(world, &registry)),
+        (Method::Get, Some(entity), None) if world.contains(entity) => {
+            request.respond_html(entity_page(world, &registry, entity))
+        }
+        (Method::Put, Some(entity), None) => {
+            let mut iter = request.body.splitn(2, ':');
+            let component = iter
+                .next()
+                .and_then(|index| index.parse::<usize>().ok())
+                .and_then(|index| registry.components.get(index));
+            let update = match (component, iter.next()) {
+                (Some(component), Some(value)) => {
+                    handles::with_resources(resources, || (component.update)(world, entity, value))
+                }
+                _ => Err(format!("unexpected component update '{}'", request.body)),
+            };
+            match update {
+                Ok(()) => request.respond_status(200),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    request.respond_error(e);
+                }
+            }
+        }
+        (Method::Post, Some(entity), Some(component)) => {
+            let success = (component.insert_default)(world, entity);
+            request.respond_status(if success { 200 } else { 404 });
+        }
+        (Method::Delete, Some(entity), Some(component)) => {
+            let success = (component.remove)(world, entity);
+            request.respond_status(if success { 200 } else { 404 });
+        }
+        _ => request.respond_status(404),
+    }
+}
+
+fn entity_list(world: &World, registry: &InspectableRegistry) -> String {
+    let mut entities: Vec<Entity> = world.query::<Entity>().collect();
+    entities.sort_by_key(|entity| entity.id());
+
+    let mut inputs = String::from("<b>Entities</b>");
+    for entity in entities {
+        let components: Vec<&str> = registry
+            .components
+            .iter()
+            .filter(|component| (component.has)(world, entity))
+            .map(|component| component.name)
+            .collect();
+
+        inputs.push_str(&format!(
+            r#"
+            <div class="row">
+                <a class="cell text-right" href="/entities/{bits}">Entity {id}</a>
+                <span class="cell">{components}</span>
+            </div>
+            "#,
+            bits = entity.to_bits(),
+            id = entity.id(),
+            components = components.join(", "),
+        ));
+    }
+
+    crate::html_page("", &inputs, "")
+}
+
+fn entity_page(world: &World, registry: &InspectableRegistry, entity: Entity) -> String {
+    let mut header = String::new();
+    let mut footer = String::new();
+    let mut types = HashSet::new();
+
+    let mut inputs = format!("<b>Entity {}</b>", entity.id());
+    let mut missing = String::new();
+
+    for (i, component) in registry.components.iter().enumerate() {
+        let submit_fn = format!("(value => handleChange('{}', value))", i);
+        match (component.html)(world, entity, submit_fn) {
+            Some(html) => {
+                (component.register_header_footer)(&mut types, &mut header, &mut footer);
+                inputs.push_str(&html);
+                inputs.push_str(&format!(
+                    r#"
+            <div class="row">
+                <span class="cell"></span>
+                <div class="cell"><button onclick="componentAction('DELETE', {})">Remove {}</button></div>
+            </div>
+            "#,
+                    i, component.name,
+                ));
+            }
+            None => missing.push_str(&format!(
+                r#"<option value="{}">{}</option>"#,
+                i, component.name
+            )),
+        }
+    }
+
+    if !missing.is_empty() {
+        inputs.push_str(&format!(
+            r#"
+            <div class="row">
+                <label for="missing-components" class="cell text-right">Add component:</label>
+                <div class="cell">
+                    <select id="missing-components">{}</select>
+                    <button onclick="componentAction('POST', document.getElementById('missing-components').value)">Add</button>
+                </div>
+            </div>
+            "#,
+            missing
+        ));
+    }
+
+    inputs.push_str(
+        r#"
+        <script>
+        const componentAction = (method, component) => fetch(location.pathname + '/' + component, { method })
+            .then(() => location.reload())
+            .catch(e => alert(e));
+        </script>
+        "#,
+    );
+
+    crate::html_page(&header, &inputs, &footer)
+}
@@ -0,0 +1,125 @@
+use std::path::{Path, PathBuf};
+
+use bevy::asset::AssetServerSettings;
+use bevy::prelude::*;
+use tiny_http::Method;
+
+use crate::inspector_server::AppRequest;
+
+/// More files than this aren't listed, so that pointing the asset folder at e.g. a home directory doesn't freeze the app.
+const MAX_FILES: usize = 10_000;
+
+/// The asset folder the `AssetServer` loads from,
+/// relative to `CARGO_MANIFEST_DIR` or the directory of the executable like bevy does it.
+fn asset_folder(resources: &Resources) -> PathBuf {
+    let root = std::env::var("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .or_else(|_| {
+            std::env::current_exe().map(|exe| exe.parent().map(Path::to_path_buf).unwrap_or(exe))
+        })
+        .unwrap_or_default();
+
+    let folder = resources.get::<AssetServerSettings>().map_or_else(
+        || "assets".to_string(),
+        |settings| settings.asset_folder.clone(),
+    );
+    root.join(folder)
+}
+
+/// Collects the paths of the files in `dir` relative to `root`, skipping hidden files and directories.
+/// Symbolic links to directories aren't followed, since they could link to one of their parents.
+fn collect_files(root: &Path, dir: &Path, extensions: &[String], files: &mut Vec<String>) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        if files.len() >= MAX_FILES {
+            return;
+        }
+
+        let path = entry.path();
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if hidden {
+            continue;
+        }
+
+        // unlike `Path::is_dir`, the file type of the entry itself doesn't follow symbolic links
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => {
+                collect_files(root, &path, extensions, files);
+                continue;
+            }
+            Ok(_) => {}
+            Err(_) => continue,
+        }
+
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        let matches = extensions.is_empty()
+            || extension.map_or(false, |extension| extensions.contains(&extension));
+        if !matches {
+            continue;
+        }
+
+        if let Ok(relative) = path.strip_prefix(root) {
+            // asset paths use forward slashes on every platform
+            let components: Vec<_> = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect();
+            files.push(components.join("/"));
+        }
+    }
+}
+
+/// Handles
+/// - `GET /files`: the files in the asset folder as `<option>`s for a `<datalist>`
+/// - `GET /files?extensions=png,jpg`: only the files with one of the extensions
+///
+/// The asset folder is scanned on another thread, so that a large folder doesn't stall the app.
+pub(crate) fn handle_request(_: &mut World, resources: &mut Resources, request: AppRequest) {
+    if *request.method() != Method::Get {
+        return request.respond_status(405);
+    }
+
+    let extensions = match request
+        .query()
+        .split('&')
+        .filter_map(|pair| pair.strip_prefix("extensions="))
+        .map(crate::html_impls::percent_decode)
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(extensions) => extensions,
+        Err(e) => {
+            eprintln!("failed to parse '{}': {}", request.query(), e);
+            return request.respond_status(400);
+        }
+    };
+    let extensions: Vec<String> = extensions
+        .iter()
+        .flat_map(|extensions| extensions.split(','))
+        .filter(|extension| !extension.is_empty())
+        .map(|extension| extension.trim().trim_start_matches('.').to_lowercase())
+        .collect();
+
+    let root = asset_folder(resources);
+    std::thread::spawn(move || {
+        let mut files = Vec::new();
+        collect_files(&root, &root, &extensions, &mut files);
+        files.sort();
+
+        let html: String = files
+            .iter()
+            .map(|file| {
+                format!(
+                    r#"<option value="{}"></option>"#,
+                    crate::html_impls::escape_html(file)
+                )
+            })
+            .collect();
+        request.respond_html(html);
+    });
+}
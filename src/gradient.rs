@@ -0,0 +1,200 @@
+use std::cmp::Ordering;
+
+use bevy::prelude::*;
+
+use crate::as_html::{AsHtml, SharedOptions};
+
+/// A color at a position of a [`Gradient`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorStop {
+    /// Between 0 and 1
+    pub position: f32,
+    pub color: Color,
+}
+
+/// A color gradient, which is edited by dragging its color stops in the inspector.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_contrib_inspector::Gradient;
+///
+/// let gradient = Gradient::new(vec![(0.0, Color::RED), (1.0, Color::BLUE)]);
+/// let purple = gradient.sample(0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    /// sorted by position
+    stops: Vec<ColorStop>,
+}
+
+impl Default for Gradient {
+    fn default() -> Self {
+        Gradient::new(vec![(0.0, Color::BLACK), (1.0, Color::WHITE)])
+    }
+}
+
+impl Gradient {
+    /// Creates a gradient from `(position, color)` pairs, which don't have to be sorted.
+    pub fn new(stops: Vec<(f32, Color)>) -> Self {
+        let mut gradient = Gradient {
+            stops: stops
+                .into_iter()
+                .map(|(position, color)| ColorStop { position, color })
+                .collect(),
+        };
+        gradient.sort();
+        gradient
+    }
+
+    /// The color stops, sorted by their position
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    pub fn add_stop(&mut self, position: f32, color: Color) {
+        self.stops.push(ColorStop { position, color });
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        self.stops.sort_by(|a, b| {
+            a.position
+                .partial_cmp(&b.position)
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+
+    /// The color at `position`, linearly interpolated between the surrounding stops.
+    ///
+    /// Positions before the first or after the last stop get the color of that stop.
+    /// An empty gradient is transparent.
+    pub fn sample(&self, position: f32) -> Color {
+        let next = self.stops.iter().position(|stop| stop.position > position);
+        let (from, to) = match next {
+            Some(0) => return self.stops[0].color,
+            Some(i) => (&self.stops[i - 1], &self.stops[i]),
+            None => {
+                return self
+                    .stops
+                    .last()
+                    .map_or(Color::rgba(0.0, 0.0, 0.0, 0.0), |stop| stop.color)
+            }
+        };
+
+        let t = (position - from.position) / (to.position - from.position);
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        Color::rgba(
+            lerp(from.color.r(), to.color.r()),
+            lerp(from.color.g(), to.color.g()),
+            lerp(from.color.b(), to.color.b()),
+            lerp(from.color.a(), to.color.a()),
+        )
+    }
+
+    /// `position,r,g,b,a` for every stop separated by `;`, with the color in sRGB
+    fn serialize(&self) -> String {
+        self.stops
+            .iter()
+            .map(|ColorStop { position, color }| {
+                format!(
+                    "{},{},{},{},{}",
+                    position,
+                    color.r(),
+                    color.g(),
+                    color.b(),
+                    color.a()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+}
+
+/// The gradient is submitted as a whole, see `static/gradient_ashtml.js`.
+impl AsHtml for Gradient {
+    type Err = String;
+    type Options = ();
+    const DEFAULT_OPTIONS: Self::Options = ();
+
+    fn footer() -> &'static str {
+        concat!(
+            "<script>",
+            include_str!("../static/gradient_ashtml.js"),
+            "</script>"
+        )
+    }
+
+    fn as_html(shared: SharedOptions<Self>, (): Self::Options, submit_fn: String) -> String {
+        format!(
+            r#"
+            <div class="row">
+                <label class="cell text-right" style="vertical-align: middle;">{label}:</label>
+                <div class="cell gradient" data-gradient="{gradient}" {readonly}>
+                    <canvas width="240" height="36" style="width: 15rem; height: 2.25rem; border: 1px solid #000"></canvas>
+                    <div class="gradient-stop">
+                        <input type="color" {disabled}>
+                        <label class="vector-component">a <input type="number" min="0" max="1" step="0.01" {disabled}></label>
+                        <button {disabled}>Remove</button>
+                    </div>
+                    <input type="hidden" oninput="{submit_fn}(this.value)">
+                </div>
+            </div>
+            "#,
+            label = shared.label,
+            gradient = shared.default.serialize(),
+            readonly = if shared.readonly { "data-readonly" } else { "" },
+            disabled = shared.disabled(),
+            submit_fn = submit_fn,
+        )
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let stops = value
+            .split(';')
+            .filter(|stop| !stop.is_empty())
+            .map(|stop| {
+                let mut components = [0.0; 5];
+                crate::html_impls::parse_components(stop, &mut components)?;
+                let [position, r, g, b, a] = components;
+                Ok((position, Color::rgba(r, g, b, a)))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Gradient::new(stops))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_are_parsed_and_sorted() {
+        let gradient = Gradient::parse("1,0,0,1,1;0,1,0,0,0.5;").unwrap();
+        assert_eq!(
+            gradient,
+            Gradient::new(vec![
+                (0.0, Color::rgba(1.0, 0.0, 0.0, 0.5)),
+                (1.0, Color::rgba(0.0, 0.0, 1.0, 1.0)),
+            ])
+        );
+        assert_eq!(Gradient::parse(&gradient.serialize()), Ok(gradient));
+        assert!(Gradient::parse("").unwrap().stops().is_empty());
+
+        assert!(Gradient::parse("0,1,0,0").is_err());
+        assert!(Gradient::parse("0,1,0,0,1,1").is_err());
+        assert!(Gradient::parse("0,red,0,0,1").is_err());
+    }
+
+    #[test]
+    fn gradients_are_sampled_between_stops() {
+        let gradient = Gradient::new(vec![
+            (0.0, Color::rgba(0.0, 0.0, 0.0, 1.0)),
+            (1.0, Color::rgba(1.0, 0.5, 0.0, 0.0)),
+        ]);
+        assert_eq!(gradient.sample(-1.0), Color::rgba(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(gradient.sample(0.5), Color::rgba(0.5, 0.25, 0.0, 0.5));
+        assert_eq!(gradient.sample(2.0), Color::rgba(1.0, 0.5, 0.0, 0.0));
+        assert_eq!(Gradient::default().stops().len(), 2);
+    }
+}
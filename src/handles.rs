@@ -0,0 +1,94 @@
+use std::cell::Cell;
+
+use bevy::asset::Asset;
+use bevy::prelude::*;
+use tiny_http::Method;
+
+use crate::assets::{handle_key, handle_label};
+use crate::inspector_server::AppRequest;
+use crate::registry::InspectableRegistry;
+
+thread_local! {
+    /// The resources of the app while an update is applied, see [`with_resources`]
+    static RESOURCES: Cell<*const Resources> = Cell::new(std::ptr::null());
+}
+
+/// Applies an `update` submitted by the inspector, during which `Handle<T>` fields can be parsed using [`resolve_handle`].
+///
+/// `AsHtml::parse` has no access to the app, so the resources are made available to it for the duration of the update.
+pub(crate) fn with_resources<R>(resources: &Resources, update: impl FnOnce() -> R) -> R {
+    /// Restores the previous resources even if the update panics
+    struct Restore(*const Resources);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            RESOURCES.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(RESOURCES.with(|current| current.replace(resources)));
+    update()
+}
+
+/// A strong handle to the loaded asset of type `T` with the `key`, see [`handle_key`].
+///
+/// This only succeeds while an update is applied using [`with_resources`].
+pub(crate) fn resolve_handle<T: Asset>(key: &str) -> Option<Handle<T>> {
+    u64::from_str_radix(key, 16).ok()?;
+
+    let resources = RESOURCES.with(Cell::get);
+    // SAFETY: the pointer is only set while `with_resources` borrows the resources
+    let resources = unsafe { resources.as_ref() }?;
+    let assets = resources.get::<Assets<T>>()?;
+    let id = assets
+        .iter()
+        .map(|(id, _)| id)
+        .find(|id| handle_key(*id) == key)?;
+    Some(assets.get_handle(id))
+}
+
+/// Handles
+/// - `GET /handles/<type>`: the loaded assets of a type as `<option>`s with their key as value and path as label.
+///   The type is the percent-encoded type name of an asset type registered with `register_inspectable_handle`.
+pub(crate) fn handle_request(_: &mut World, resources: &mut Resources, request: AppRequest) {
+    if *request.method() != Method::Get {
+        return request.respond_status(405);
+    }
+
+    let registry = match resources.get::<InspectableRegistry>() {
+        Some(registry) => registry,
+        None => return request.respond_status(404),
+    };
+    let type_name = request.path()["/handles".len()..].trim_matches('/');
+    let type_name = crate::html_impls::percent_decode(type_name).unwrap_or_default();
+    let ty = match registry
+        .handle_types
+        .iter()
+        .find(|ty| ty.type_name == type_name)
+    {
+        Some(ty) => ty,
+        None => return request.respond_status(404),
+    };
+
+    let ids = match (ty.handles)(resources) {
+        Some(ids) => ids,
+        None => return request.respond_status(404),
+    };
+
+    let mut options: Vec<(String, String)> = ids
+        .into_iter()
+        .map(|id| (handle_label(resources, id), handle_key(id)))
+        .collect();
+    options.sort();
+
+    let html: String = options
+        .iter()
+        .map(|(label, key)| {
+            format!(
+                r#"<option value="{}">{}</option>"#,
+                key,
+                crate::html_impls::escape_html(label)
+            )
+        })
+        .collect();
+    request.respond_html(html);
+}
@@ -1,10 +1,154 @@
 use crate::{as_html::AsHtml, as_html::SharedOptions};
 use bevy::{math::const_vec2, prelude::*};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
+/// Whether the `NumberAttributes` `$options` of `$ty` bound the number, see [`NumberAttributes`].
+/// They're compared in `$ty`, since converting them to `f64` would round a `max` close to `u64::MAX` up to it.
+macro_rules! is_bounded {
+    ($ty:ty, $options:expr) => {
+        // the full range of `u8` and `i8` fits on a slider,
+        // and a minimum of zero is a meaningful bound for unsigned types
+        std::mem::size_of::<$ty>() == 1
+            || (($options.min > <$ty>::MIN || <$ty>::MIN as f64 >= 0.0)
+                && $options.max < <$ty>::MAX)
+    };
+}
+
+mod containers;
+mod flags;
+mod handle;
+mod path;
+mod range;
+mod time;
+
+pub(crate) use containers::percent_decode;
+pub use flags::{flags_html, parse_flag_update, UnitEnum};
+pub use path::{AssetFile, PathAttributes};
+pub use time::DurationAttributes;
+
+/// Registered in [`AsHtml::register_header_footer`] by types whose HTML has parts marked with `data-live`,
+/// like the elapsed time of a `Timer`. These parts are refreshed with the values from the game
+/// every `watch_interval` milliseconds, even if the field isn't readonly.
+#[doc(hidden)]
+pub struct LiveParts;
+
+/// Whether the HTML of `T` has parts which are refreshed by `startWatching`, see [`LiveParts`]
+#[doc(hidden)]
+pub fn has_live_parts<T: AsHtml>() -> bool {
+    let mut types = std::collections::HashSet::new();
+    T::register_header_footer(&mut types, &mut String::new(), &mut String::new());
+    types.contains(&std::any::TypeId::of::<LiveParts>())
+}
+
+/// The attributes of numbers.
+///
+/// If `max` is the largest value of the type or `min` the smallest value of a signed type
+/// (or they're infinite for floats), the number is unbounded: it's changed by `step` per pixel
+/// when dragging it and can be typed in after clicking it.
+/// Otherwise dragging it moves it between `min` and `max`, which is always the case for `u8` and `i8`.
+///
+/// The browser handles numbers as `f64`, so unbounded numbers can only be dragged
+/// between -2^53 and 2^53, where every integer is exact. Larger values have to be typed in.
+#[derive(Clone)]
 pub struct NumberAttributes<T> {
     pub min: T,
     pub max: T,
     pub step: T,
+    /// How dragging the number maps to its value between `min` and `max`:
+    /// `"linear"`, `"log"` or `"pow(n)"`.
+    /// Non-linear scales need a bounded `min` and `max`, otherwise the number is dragged linearly.
+    ///
+    /// With a non-linear scale, values are rounded to 4 significant digits
+    /// and `step` only applies if it's a whole number.
+    pub scale: &'static str,
+    /// Shows the number as an angle in degrees with a dial, wrapping around after a full turn.
+    /// The angle starts at `min` if it's bounded, otherwise at zero.
+    pub angle: bool,
+    /// The unit the angle is stored in: `"radians"` or `"degrees"`
+    pub angle_unit: &'static str,
+}
+
+/// Checks `NumberAttributes::scale`, falling back to a linear scale if it's invalid.
+///
+/// The scales of `Inspectable` fields are already rejected by the derive if they are invalid.
+fn number_scale(scale: &'static str, min: f64, max: f64, bounded: bool) -> &'static str {
+    let exponent = scale
+        .strip_prefix("pow(")
+        .and_then(|scale| scale.strip_suffix(')'))
+        .map(|exponent| exponent.trim().parse::<f64>());
+
+    match (scale, exponent) {
+        (_, _) if !bounded => "linear",
+        ("log", _) if min > 0.0 && max > min => scale,
+        (_, Some(Ok(exponent))) if exponent > 0.0 => scale,
+        _ => "linear",
+    }
+}
+
+/// The number of `unit`s in a full turn, see `NumberAttributes::angle_unit`
+fn full_turn(unit: &str) -> Option<f64> {
+    match unit {
+        "radians" => Some(std::f64::consts::TAU),
+        "degrees" => Some(360.0),
+        _ => None,
+    }
+}
+
+/// Wraps `angle` into a full turn beginning at `start`
+fn wrap_angle(angle: f64, start: f64, full_turn: f64) -> f64 {
+    start + (angle - start).rem_euclid(full_turn)
+}
+
+/// A dial and the angle in degrees, see `angleValue` in `static/number_ashtml.js`
+fn angle_html(
+    label: &str,
+    angle: f64,
+    unit: &str,
+    start: f64,
+    disabled: &str,
+    submit_fn: &str,
+) -> String {
+    let unit = if full_turn(unit).is_some() {
+        unit
+    } else {
+        eprintln!(
+            "invalid angle unit '{}' of '{}', expected \"radians\" or \"degrees\"",
+            unit, label
+        );
+        "radians"
+    };
+    let full_turn = full_turn(unit).unwrap();
+    let degrees = wrap_angle(angle, start, full_turn) / full_turn * 360.0;
+
+    format!(
+        r#"
+            <div class="row">
+                <label for="{label}" class="cell text-right" style="vertical-align: middle;">{label}:</label>
+                <div class="cell angle" data-angle-unit="{unit}" data-angle-start="{start}">
+                    <canvas width="48" height="48" style="width: 2rem; height: 2rem; vertical-align: middle;"></canvas>
+                    <input type="number" step="any" value="{degrees}" {disabled} oninput="{submit_fn}(angleValue(this))" id="{label}">°
+                </div>
+            </div>
+            "#,
+        label = label,
+        unit = unit,
+        start = start / full_turn * 360.0,
+        degrees = (degrees * 10_000.0).round() / 10_000.0,
+        disabled = disabled,
+        submit_fn = submit_fn,
+    )
+}
+
+/// `name="value"` if `value` is finite
+fn finite_attribute(name: &str, value: f64, display: impl std::fmt::Display) -> String {
+    if value.is_finite() {
+        format!(r#"{}="{}""#, name, display)
+    } else {
+        String::new()
+    }
 }
 
 macro_rules! impl_ashtml_for_int {
@@ -17,11 +161,12 @@ macro_rules! impl_ashtml_for_int {
             fn header() -> &'static str {
                 concat!(
                     "<script>",
-                    r#"var Numscrubber={};Numscrubber.init=function(){for(var a=document.querySelectorAll("input"),b=0;b<a.length;b++)if("number"==a[b].type&&null!==a[b].getAttribute("data-numscrubber")){a[b].readOnly=!0,a[b].setAttribute("style","-moz-appearance: textfield");var c=document.createElement("span");document.body.appendChild(c),a[b].parentElement.replaceChild(c,a[b]),c.style.position="relative",c.appendChild(a[b]),c.style.width=a[b].offsetWidth+"px",c.style.height=a[b].offsetHeight+"px";var d=document.createElement("input");d.setAttribute("type","range"),c.appendChild(d),""!=a[b].getAttribute("disabled")&&1!=a[b].getAttribute("disabled")||d.setAttribute("disabled",!0),d.setAttribute("step",a[b].getAttribute("step")),d.value=a[b].value,d.min=a[b].min,d.max=a[b].max;var e=a[b].currentStyle||window.getComputedStyle(a[b]);d.style.position="absolute",d.style.margin=e.margin,d.style.left=0,d.style.border="1px solid transparent",d.style.opacity=0,d.style.cursor="e-resize",d.style.width=a[b].offsetWidth+"px",d.style.height=a[b].offsetHeight+"px",function(b){d.addEventListener("input",function(){a[b].value=this.value;var c=new Event("input");a[b].dispatchEvent(c)})}(b)}};"#,
-                    "</script>")
+                    include_str!("../static/number_ashtml.js"),
+                    "</script>"
+                )
             }
             fn footer() -> &'static str {
-                "<script>Numscrubber.init()</script>"
+                "<script>initNumscrubbers(document); initAngleDials(document)</script>"
             }
 
             // we overwrite this and only check for u8, since the footer and header is the same for all number types
@@ -37,22 +182,54 @@ macro_rules! impl_ashtml_for_int {
             }
 
             fn as_html(shared_options: crate::as_html::SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+                let (min, max) = (options.min as f64, options.max as f64);
+                if options.angle {
+                    let value = shared_options.default as f64;
+                    // wrap around from `min` if it's bounded
+                    let start = if options.min > <$ty>::MIN { min } else { 0.0 };
+                    return angle_html(&shared_options.label, value, options.angle_unit, start, shared_options.disabled(), &submit_fn);
+                }
+
+                let bounded = is_bounded!($ty, options);
+
                 format!(r#"
             <div class="row">
                 <label for="{label}" class="cell text-right">{label}:</label>
-                <input class="cell" data-numscrubber type="number" min="{}" max="{}" step="{}" value="{value}" oninput="{}(this.value)" id="{label}">
+                <input class="cell" data-numscrubber="{mode}" data-scale="{scale}" type="number" {min} {max} step="{}" value="{value}" {disabled} oninput="{submit}(this.value)" id="{label}">
             </div>
             "#,
-                    options.min, options.max, options.step,
+                    options.step,
                     submit = submit_fn,
+                    mode = if bounded { "range" } else { "drag" },
+                    min = finite_attribute("min", min, options.min),
+                    max = finite_attribute("max", max, options.max),
+                    scale = number_scale(options.scale, min, max, bounded),
                     value = shared_options.default,
                     label = shared_options.label,
+                    disabled = shared_options.disabled(),
                 )
             }
 
             fn parse(value: &str) -> Result<Self, Self::Err> {
                 value.parse()
             }
+
+            /// Angles are wrapped around and values outside of a non-linear scale are clamped to `min` and `max`
+            fn update_with_options(&mut self, value: &str, options: &Self::Options) -> Result<(), Self::Err> {
+                let value = Self::parse(value)?;
+                *self = match options.scale {
+                    _ if options.angle => {
+                        let full_turn = full_turn(options.angle_unit).unwrap_or(std::f64::consts::TAU);
+                        let start = if options.min > <$ty>::MIN { options.min as f64 } else { 0.0 };
+                        wrap_angle(value as f64, start, full_turn) as $ty
+                    }
+                    "linear" => value,
+                    _ if value < options.min => options.min,
+                    _ if value > options.max => options.max,
+                    _ => value,
+                };
+                Ok(())
+            }
         }
     };
 
@@ -61,38 +238,296 @@ macro_rules! impl_ashtml_for_int {
     }
 }
 
-impl_ashtml_for_int!(u8 => NumberAttributes { min: std::u8::MIN, max: std::u8::MAX, step: 1 } ; std::num::ParseIntError);
-impl_ashtml_for_int!(i8 => NumberAttributes { min: std::i8::MIN, max: std::i8::MAX, step: 1 } ; std::num::ParseIntError);
+impl_ashtml_for_int!(u8 => NumberAttributes { min: std::u8::MIN, max: std::u8::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
+impl_ashtml_for_int!(i8 => NumberAttributes { min: std::i8::MIN, max: std::i8::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
 
-impl_ashtml_for_int!(u16, u32, u64, u128, usize => NumberAttributes { min: 0, max: 100, step: 1 } ; std::num::ParseIntError);
-impl_ashtml_for_int!(i16, i32, i64, i128, isize => NumberAttributes { min: 0, max: 100, step: 1 } ; std::num::ParseIntError);
+impl_ashtml_for_int!(u16 => NumberAttributes { min: 0, max: std::u16::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
+impl_ashtml_for_int!(u32 => NumberAttributes { min: 0, max: std::u32::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
+impl_ashtml_for_int!(u64 => NumberAttributes { min: 0, max: std::u64::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
+impl_ashtml_for_int!(u128 => NumberAttributes { min: 0, max: std::u128::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
+impl_ashtml_for_int!(usize => NumberAttributes { min: 0, max: std::usize::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
+impl_ashtml_for_int!(i16 => NumberAttributes { min: std::i16::MIN, max: std::i16::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
+impl_ashtml_for_int!(i32 => NumberAttributes { min: std::i32::MIN, max: std::i32::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
+impl_ashtml_for_int!(i64 => NumberAttributes { min: std::i64::MIN, max: std::i64::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
+impl_ashtml_for_int!(i128 => NumberAttributes { min: std::i128::MIN, max: std::i128::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
+impl_ashtml_for_int!(isize => NumberAttributes { min: std::isize::MIN, max: std::isize::MAX, step: 1, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseIntError);
 
-impl_ashtml_for_int!(f32, f64 => NumberAttributes { min: 0.0, max: 1.0, step: 0.01 } ; std::num::ParseFloatError );
+impl_ashtml_for_int!(f32, f64 => NumberAttributes { min: f64::NEG_INFINITY as _, max: f64::INFINITY as _, step: 0.01, scale: "linear", angle: false, angle_unit: "radians" } ; std::num::ParseFloatError );
+
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[derive(Clone)]
+pub struct StringAttributes {
+    /// Renders a `<textarea>` with `rows` lines instead of a single line input
+    pub multiline: bool,
+    pub rows: usize,
+    /// The maximum number of characters, unlimited by default
+    pub max_length: usize,
+    pub placeholder: &'static str,
+    pub monospace: bool,
+    /// A regular expression the whole string has to match, e.g. `"[A-Z_]+"`.
+    /// It is checked in the browser and when the field is updated.
+    pub pattern: &'static str,
+}
 
 impl AsHtml for String {
-    type Err = std::convert::Infallible;
+    type Err = String;
+    type Options = StringAttributes;
+    const DEFAULT_OPTIONS: Self::Options = StringAttributes {
+        multiline: false,
+        rows: 4,
+        max_length: usize::MAX,
+        placeholder: "",
+        monospace: false,
+        pattern: "",
+    };
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let mut attributes = String::new();
+        if options.max_length != usize::MAX {
+            attributes.push_str(&format!(r#" maxlength="{}""#, options.max_length));
+        }
+        if !options.placeholder.is_empty() {
+            attributes.push_str(&format!(
+                r#" placeholder="{}""#,
+                escape_html(options.placeholder)
+            ));
+        }
+        if options.monospace {
+            attributes.push_str(r#" style="font-family: monospace""#);
+        }
+        // checked by `checkPattern` in script.js, since textareas don't support the `pattern` attribute
+        if !options.pattern.is_empty() {
+            attributes.push_str(&format!(
+                r#" data-pattern="{}""#,
+                escape_html(options.pattern)
+            ));
+        }
+
+        let input = if options.multiline {
+            format!(
+                r#"<textarea class="cell" rows="{rows}"{attributes} {disabled} oninput="checkPattern(this) && {submit_fn}(this.value)" id="{label}">{value}</textarea>"#,
+                rows = options.rows,
+                attributes = attributes,
+                disabled = shared.disabled(),
+                submit_fn = submit_fn,
+                label = shared.label,
+                value = escape_html(&shared.default),
+            )
+        } else {
+            format!(
+                r#"<input class="cell" type="text" value="{value}"{attributes} {disabled} oninput="checkPattern(this) && {submit_fn}(this.value)" id="{label}">"#,
+                value = escape_html(&shared.default),
+                attributes = attributes,
+                disabled = shared.disabled(),
+                submit_fn = submit_fn,
+                label = shared.label,
+            )
+        };
+
+        format!(
+            r#"
+            <div class="row">
+                <label for="{label}" class="cell text-right">{label}:</label>
+                {input}
+            </div>
+            "#,
+            label = shared.label,
+            input = input,
+        )
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(value.to_string())
+    }
+
+    /// Rejects strings which are too long or don't match the `pattern`
+    fn update_with_options(
+        &mut self,
+        value: &str,
+        options: &Self::Options,
+    ) -> Result<(), Self::Err> {
+        if value.chars().count() > options.max_length {
+            return Err(format!(
+                "'{}' is longer than {} characters",
+                value, options.max_length
+            ));
+        }
+        if !options.pattern.is_empty() {
+            if !matches_pattern(options.pattern, value)? {
+                return Err(format!(
+                    "'{}' doesn't match the pattern '{}'",
+                    value, options.pattern
+                ));
+            }
+        }
+
+        self.update(value)
+    }
+}
+
+/// The compiled `StringAttributes::pattern`s, so that they aren't compiled again for every update
+static PATTERNS: Lazy<Mutex<HashMap<&'static str, Result<Regex, String>>>> =
+    Lazy::new(Default::default);
+
+/// Whether the whole `value` matches `pattern`, like the `pattern` attribute of inputs
+fn matches_pattern(pattern: &'static str, value: &str) -> Result<bool, String> {
+    let mut patterns = PATTERNS.lock().unwrap();
+    let regex = patterns.entry(pattern).or_insert_with(|| {
+        Regex::new(&format!("^(?:{})$", pattern))
+            .map_err(|e| format!("invalid pattern '{}': {}", pattern, e))
+    });
+    match regex {
+        Ok(regex) => Ok(regex.is_match(value)),
+        Err(e) => Err(e.clone()),
+    }
+}
+
+/// Rendered and checked like a `String`
+impl AsHtml for std::borrow::Cow<'static, str> {
+    type Err = String;
+    type Options = StringAttributes;
+    const DEFAULT_OPTIONS: Self::Options = String::DEFAULT_OPTIONS;
+
+    fn register_header_footer(
+        types: &mut std::collections::HashSet<std::any::TypeId>,
+        header: &mut String,
+        footer: &mut String,
+    ) {
+        String::register_header_footer(types, header, footer);
+    }
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let string = SharedOptions {
+            label: shared.label,
+            default: shared.default.into_owned(),
+            readonly: shared.readonly,
+        };
+        String::as_html(string, options, submit_fn)
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(std::borrow::Cow::Owned(value.to_string()))
+    }
+
+    fn update_with_options(
+        &mut self,
+        value: &str,
+        options: &Self::Options,
+    ) -> Result<(), Self::Err> {
+        let mut string = String::new();
+        string.update_with_options(value, options)?;
+        *self = std::borrow::Cow::Owned(string);
+        Ok(())
+    }
+}
+
+impl AsHtml for char {
+    type Err = String;
     type Options = ();
     const DEFAULT_OPTIONS: Self::Options = ();
 
     fn as_html(shared: SharedOptions<Self>, (): Self::Options, submit_fn: String) -> String {
+        // characters outside of the BMP have a length of 2 in js
         format!(
             r#"
             <div class="row">
                 <label for="{label}" class="cell text-right">{label}:</label>
-                <input class="cell" type="text" value="{value}" oninput="{}(this.value)" id="{label}">
+                <input class="cell" type="text" size="2" value="{value}" {disabled} oninput="[...this.value].length === 1 && {submit_fn}(this.value)" id="{label}">
             </div>
             "#,
-            submit_fn,
-            value = shared.default,
             label = shared.label,
+            value = escape_html(&shared.default.to_string()),
+            disabled = shared.disabled(),
+            submit_fn = submit_fn,
         )
     }
 
     fn parse(value: &str) -> Result<Self, Self::Err> {
-        Ok(value.to_string())
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(format!("expected a single character, got '{}'", value)),
+        }
     }
 }
 
+/// Rendered like the underlying integer.
+/// Unsigned numbers have a `min` of at least 1, signed numbers keep their bounds and reject zero when it is submitted.
+macro_rules! impl_ashtml_for_nonzero {
+    (@default_options unsigned $ty:ty) => {
+        NumberAttributes { min: 1, ..<$ty as AsHtml>::DEFAULT_OPTIONS }
+    };
+    (@default_options signed $ty:ty) => {
+        <$ty as AsHtml>::DEFAULT_OPTIONS
+    };
+    (@options unsigned $options:expr) => {
+        NumberAttributes { min: $options.min.max(1), ..$options }
+    };
+    (@options signed $options:expr) => {
+        $options
+    };
+    ($sign:ident: $($nonzero:ident($ty:ty)),+) => {$(
+        impl AsHtml for std::num::$nonzero {
+            type Err = String;
+            type Options = NumberAttributes<$ty>;
+            const DEFAULT_OPTIONS: Self::Options = impl_ashtml_for_nonzero!(@default_options $sign $ty);
+
+            fn register_header_footer(
+                types: &mut std::collections::HashSet<std::any::TypeId>,
+                header: &mut String,
+                footer: &mut String,
+            ) {
+                <$ty>::register_header_footer(types, header, footer);
+            }
+
+            fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+                let number = SharedOptions {
+                    label: shared.label,
+                    default: shared.default.get(),
+                    readonly: shared.readonly,
+                };
+                let options = impl_ashtml_for_nonzero!(@options $sign options);
+                <$ty>::as_html(number, options, submit_fn)
+            }
+
+            fn parse(value: &str) -> Result<Self, Self::Err> {
+                value.parse().map_err(|e| format!("{}", e))
+            }
+
+            fn update_with_options(&mut self, value: &str, options: &Self::Options) -> Result<(), Self::Err> {
+                let options = impl_ashtml_for_nonzero!(@options $sign options.clone());
+                let mut number = self.get();
+                number.update_with_options(value, &options).map_err(|e| format!("{}", e))?;
+                *self = Self::new(number).ok_or_else(|| "the number can't be zero".to_string())?;
+                Ok(())
+            }
+        }
+    )+};
+}
+
+impl_ashtml_for_nonzero!(
+    unsigned: NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32),
+    NonZeroU64(u64),
+    NonZeroU128(u128),
+    NonZeroUsize(usize)
+);
+impl_ashtml_for_nonzero!(
+    signed: NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroI128(i128),
+    NonZeroIsize(isize)
+);
+
 impl AsHtml for bool {
     type Err = std::str::ParseBoolError;
     type Options = ();
@@ -103,12 +538,13 @@ impl AsHtml for bool {
             r#"
             <div class="row">
                 <label for="{label}" class="cell text-right">{label}:</label>
-                <input class="cell" type="checkbox" {checked} oninput="{}(this.checked)" id="{label}">
+                <input class="cell" type="checkbox" {checked} {disabled} oninput="{}(this.checked)" id="{label}">
             </div>
             "#,
             submit_fn,
             checked = if shared.default { "checked" } else { "" },
             label = shared.label,
+            disabled = shared.disabled(),
         )
     }
 
@@ -117,54 +553,377 @@ impl AsHtml for bool {
     }
 }
 
+/// The attributes of derived enums
+#[derive(Clone)]
+pub struct EnumAttributes {
+    /// How the variant is selected: `"dropdown"`, `"radio"` or `"segmented"`.
+    ///
+    /// By default, enums with more than [`EnumAttributes::MAX_RADIO_VARIANTS`] variants are rendered as a dropdown,
+    /// the others using radio buttons.
+    pub style: &'static str,
+}
+impl EnumAttributes {
+    pub const DEFAULT: EnumAttributes = EnumAttributes { style: "" };
+    pub const MAX_RADIO_VARIANTS: usize = 4;
+}
+
+/// The input selecting the variant of a derived enum, `variants` contains the name and label of every variant.
+#[doc(hidden)]
+pub fn enum_variant_selector(
+    name: &str,
+    variants: &[(&str, &str)],
+    current: &str,
+    options: &EnumAttributes,
+    disabled: &str,
+    submit_fn: &str,
+) -> String {
+    let style = match options.style {
+        "" if variants.len() > EnumAttributes::MAX_RADIO_VARIANTS => "dropdown",
+        "" => "radio",
+        "dropdown" | "radio" | "segmented" => options.style,
+        other => {
+            eprintln!(
+                "unknown enum style '{}', expected 'dropdown', 'radio' or 'segmented'",
+                other
+            );
+            "dropdown"
+        }
+    };
+
+    if style == "dropdown" {
+        let options: String = variants
+            .iter()
+            .map(|(variant, label)| {
+                format!(
+                    r#"<option value="{}" {}>{}</option>"#,
+                    variant,
+                    if *variant == current { "selected" } else { "" },
+                    label
+                )
+            })
+            .collect();
+        return format!(
+            r#"<select {} oninput="{}(selectEnumVariant(this))">{}</select>"#,
+            disabled, submit_fn, options
+        );
+    }
+
+    let radios: String = variants
+        .iter()
+        .map(|(variant, label)| {
+            format!(
+                r#"
+                <label>
+                    <input type="radio" value="{variant}" name="{name}" {checked} {disabled} oninput="{submit_fn}(selectEnumVariant(this))"/>
+                    <span>{label}</span>
+                </label>
+                "#,
+                variant = variant,
+                name = name,
+                checked = if *variant == current { "checked" } else { "" },
+                disabled = disabled,
+                submit_fn = submit_fn,
+                label = label,
+            )
+        })
+        .collect();
+
+    if style == "segmented" {
+        format!(r#"<div class="segmented">{}</div>"#, radios)
+    } else {
+        radios
+    }
+}
+
+/// The hex value of the color for `<input type="color">`, which only supports 8-bit sRGB without alpha
 fn color_to_string(c: &Color) -> String {
     use std::fmt::Write;
 
-    let mut s = String::with_capacity(6);
+    let to_u8 = |component: f32| (component.max(0.0).min(1.0) * 255.0).round() as u8;
+
+    let mut s = String::with_capacity(7);
     s.push('#');
-    write!(s, "{:02x}", (c.r() * 255.0) as u8).unwrap();
-    write!(s, "{:02x}", (c.g() * 255.0) as u8).unwrap();
-    write!(s, "{:02x}", (c.b() * 255.0) as u8).unwrap();
+    write!(s, "{:02x}", to_u8(c.r())).unwrap();
+    write!(s, "{:02x}", to_u8(c.g())).unwrap();
+    write!(s, "{:02x}", to_u8(c.b())).unwrap();
     s
 }
-fn string_to_color(s: &str) -> Result<Color, ()> {
-    if !s.starts_with('#') || !s.len() == 7 {
-        return Err(());
+/// Parses `#rrggbb` or `#rrggbbaa`
+fn string_to_color(s: &str) -> Result<Color, String> {
+    let invalid = || format!("invalid hex color '{}'", s);
+    if !s.starts_with('#') || !(s.len() == 7 || s.len() == 9) {
+        return Err(invalid());
     }
 
-    let r = u8::from_str_radix(&s[1..=2], 16).map_err(drop)?;
-    let g = u8::from_str_radix(&s[3..=4], 16).map_err(drop)?;
-    let b = u8::from_str_radix(&s[5..=6], 16).map_err(drop)?;
+    let component = |i: usize| {
+        u8::from_str_radix(&s[i..i + 2], 16)
+            .map(|c| c as f32 / 255.0)
+            .map_err(|_| invalid())
+    };
+    let alpha = if s.len() == 9 { component(7)? } else { 1.0 };
+
+    Ok(Color::rgba(
+        component(1)?,
+        component(3)?,
+        component(5)?,
+        alpha,
+    ))
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// hue in degrees, saturation and value from sRGB
+fn rgb_to_hsv([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+    [hue, saturation, max]
+}
+fn hsv_to_rgb([hue, saturation, value]: [f32; 3]) -> [f32; 3] {
+    let chroma = value * saturation;
+    let hue = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+
+    let [r, g, b] = match hue as u32 {
+        0 => [chroma, x, 0.0],
+        1 => [x, chroma, 0.0],
+        2 => [0.0, chroma, x],
+        3 => [0.0, x, chroma],
+        4 => [x, 0.0, chroma],
+        _ => [chroma, 0.0, x],
+    };
+    let m = value - chroma;
+    [r + m, g + m, b + m]
+}
 
-    let r = r as f32 / 255.0;
-    let g = g as f32 / 255.0;
-    let b = b as f32 / 255.0;
+/// The components of `color` in `mode`, see [`ColorAttributes::mode`]
+fn color_components(color: &Color, mode: &str) -> [f32; 3] {
+    let rgb = [color.r(), color.g(), color.b()];
+    match mode {
+        "hsv" => rgb_to_hsv(rgb),
+        "linear" => [
+            srgb_to_linear(rgb[0]),
+            srgb_to_linear(rgb[1]),
+            srgb_to_linear(rgb[2]),
+        ],
+        _ => rgb,
+    }
+}
+fn color_from_components(mode: &str, components: [f32; 3], alpha: f32) -> Result<Color, String> {
+    let [r, g, b] = match mode {
+        "rgb" => components,
+        "hsv" => hsv_to_rgb(components),
+        "linear" => [
+            linear_to_srgb(components[0]),
+            linear_to_srgb(components[1]),
+            linear_to_srgb(components[2]),
+        ],
+        other => return Err(format!("unknown color mode '{}'", other)),
+    };
+    Ok(Color::rgba(r, g, b, alpha))
+}
 
-    Ok(Color::rgb(r, g, b))
+#[derive(Clone)]
+pub struct ColorAttributes {
+    /// The components which are edited:
+    /// `"rgb"` for sRGB, `"hsv"` for hue (in degrees), saturation and value or `"linear"` for linear RGB.
+    pub mode: &'static str,
+    /// Whether the alpha channel can be edited
+    pub alpha: bool,
+    pub step: f32,
 }
+
+/// Edited as floats in the `mode` of the [`ColorAttributes`], and additionally using a color picker.
+///
+/// Besides `#rrggbb` and `#rrggbbaa`, it parses `<mode>:<components>` with three components, or four including the alpha.
+/// When updated without the alpha, it is left unchanged.
 impl AsHtml for Color {
-    type Err = ();
-    type Options = ();
+    type Err = String;
+    type Options = ColorAttributes;
 
-    const DEFAULT_OPTIONS: Self::Options = ();
+    const DEFAULT_OPTIONS: Self::Options = ColorAttributes {
+        mode: "rgb",
+        alpha: true,
+        step: 0.01,
+    };
+
+    fn header() -> &'static str {
+        concat!(
+            "<script>",
+            include_str!("../static/color_ashtml.js"),
+            "</script>"
+        )
+    }
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let mode = match options.mode {
+            "rgb" | "hsv" | "linear" => options.mode,
+            other => {
+                eprintln!(
+                    "unknown color mode '{}', expected 'rgb', 'hsv' or 'linear'",
+                    other
+                );
+                "rgb"
+            }
+        };
+        let names = if mode == "hsv" {
+            ["h", "s", "v"]
+        } else {
+            ["r", "g", "b"]
+        };
+        let disabled = shared.disabled();
+
+        let component = |name: &str, value: f32, max: &str, step: f32| {
+            format!(
+                r#"<label class="vector-component">{name} <input type="number" min="0" {max} step="{step}" value="{value}" {disabled} oninput="{submit_fn}(colorFromComponents(this))"></label>"#,
+                name = name,
+                max = max,
+                step = step,
+                value = value,
+                disabled = disabled,
+                submit_fn = submit_fn,
+            )
+        };
+
+        let mut components: String = color_components(&shared.default, mode)
+            .iter()
+            .zip(&names)
+            .map(|(&value, &name)| match name {
+                "h" => component(name, value, r#"max="360""#, 1.0),
+                _ => component(name, value, "", options.step),
+            })
+            .collect();
+        if options.alpha {
+            components.push_str(&component(
+                "a",
+                shared.default.a(),
+                r#"max="1""#,
+                options.step,
+            ));
+        }
 
-    fn as_html(shared: SharedOptions<Self>, (): Self::Options, submit_fn: String) -> String {
         format!(
             r#"<div class="row">
                 <label for="{label}" class="cell text-right">{label}:</label>
-                <input class="cell" type="color" value={default} oninput="{}(this.value)" id="{label}">
+                <div class="cell color" data-color-mode="{mode}">
+                    <input type="color" value="{hex}" {disabled} oninput="{submit_fn}(colorFromHex(this))" id="{label}">
+                    {components}
+                </div>
             </div>"#,
-            submit_fn,
             label = shared.label,
-            default = color_to_string(&shared.default),
+            mode = mode,
+            hex = color_to_string(&shared.default),
+            disabled = disabled,
+            submit_fn = submit_fn,
+            components = components,
         )
     }
 
     fn parse(value: &str) -> Result<Self, Self::Err> {
-        string_to_color(value)
+        if value.starts_with('#') {
+            return string_to_color(value);
+        }
+
+        let mut iter = value.splitn(2, ':');
+        let (mode, components) = iter
+            .next()
+            .zip(iter.next())
+            .ok_or_else(|| format!("expected '<mode>:<components>', got '{}'", value))?;
+
+        if components.split(',').count() == 4 {
+            let mut rgba = [0.0; 4];
+            parse_components(components, &mut rgba)?;
+            color_from_components(mode, [rgba[0], rgba[1], rgba[2]], rgba[3])
+        } else {
+            let mut rgb = [0.0; 3];
+            parse_components(components, &mut rgb)?;
+            color_from_components(mode, rgb, 1.0)
+        }
+    }
+
+    fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+        let has_alpha = match value.strip_prefix('#') {
+            Some(hex) => hex.len() == 8,
+            None => value.split(',').count() == 4,
+        };
+
+        let color = Color::parse(value)?;
+        let alpha = if has_alpha { color.a() } else { self.a() };
+        *self = Color::rgba(color.r(), color.g(), color.b(), alpha);
+        Ok(())
     }
 }
 
+impl AsHtml for ColorMaterial {
+    type Err = String;
+    type Options = ();
+    const DEFAULT_OPTIONS: Self::Options = ();
+
+    fn as_html(shared: SharedOptions<Self>, (): Self::Options, submit_fn: String) -> String {
+        let color = SharedOptions {
+            label: std::borrow::Cow::Borrowed("color"),
+            default: shared.default.color,
+            readonly: shared.readonly,
+        };
+        let color_submit_fn = format!("((value) => {}('color:'+value))", submit_fn);
+
+        format!(
+            "<b>{}</b>{}",
+            shared.label,
+            Color::as_html(color, Color::DEFAULT_OPTIONS, color_submit_fn)
+        )
+    }
+
+    fn register_header_footer(
+        types: &mut std::collections::HashSet<std::any::TypeId>,
+        header: &mut String,
+        footer: &mut String,
+    ) {
+        Color::register_header_footer(types, header, footer);
+    }
+
+    fn parse(_: &str) -> Result<Self, Self::Err> {
+        Err("a ColorMaterial can only be updated field by field".to_string())
+    }
+
+    fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+        let mut iter = value.splitn(2, ':');
+        match (iter.next(), iter.next()) {
+            (Some("color"), Some(color)) => {
+                self.color.update(color)?;
+                Ok(())
+            }
+            _ => Err(format!("expected 'color:<value>', got '{}'", value)),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct Vec2Attributes {
     pub min: Vec2,
     pub max: Vec2,
@@ -190,6 +949,7 @@ impl AsHtml for Vec2 {
                         data-vec2d-default="{default_x},{default_y}"
                         data-vec2d-min="{min_x},{min_y}"
                         data-vec2d-max="{max_x},{max_y}"
+                        {readonly}
                         width="240" height="240"
                         id="{label}"
                         style="width: 12rem; height: 12rem; border: 1px solid #000" />
@@ -197,12 +957,15 @@ impl AsHtml for Vec2 {
             </div>
         </div>
         <script>
-            let {label}Output = document.getElementById("{label}-output");
-            document.getElementById("{label}").addEventListener("vec2d-data", (e) => {{
-                const {{ x, y }} = e.detail;
-                {label}Output.textContent = '(' + x.toFixed(2) + ', ' + y.toFixed(2) + ')';
-                {submit_fn}(x.toString() + ',' + y.toString());
-            }});
+            // in a block, since the label isn't necessarily a valid identifier, e.g. in the entries of a map
+            {{
+                const output = document.getElementById("{label}-output");
+                document.getElementById("{label}").addEventListener("vec2d-data", (e) => {{
+                    const {{ x, y }} = e.detail;
+                    output.textContent = '(' + x.toFixed(2) + ', ' + y.toFixed(2) + ')';
+                    {submit_fn}(x.toString() + ',' + y.toString());
+                }});
+            }}
         </script>
         "#,
             default_x = shared.default.x,
@@ -213,6 +976,11 @@ impl AsHtml for Vec2 {
             max_y = options.max.y,
             submit_fn = submit_fn,
             label = shared.label,
+            readonly = if shared.readonly {
+                "data-vec2d-readonly"
+            } else {
+                ""
+            },
         )
     }
 
@@ -235,3 +1003,480 @@ impl AsHtml for Vec2 {
         Ok(Vec2::new(x, y))
     }
 }
+
+/// The attributes of vectors, applied to every component.
+///
+/// The components are unbounded by default, infinite bounds are left out of the inputs.
+#[derive(Clone)]
+pub struct VecAttributes {
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+}
+
+/// A row with a number input for each of the `components`.
+/// Every input submits all components, separated by commas.
+///
+/// If `uniform_lock` is set, there is an additional checkbox (checked if `Some(true)`)
+/// which sets all components to the same value while it is checked.
+fn vector_html(
+    label: &str,
+    components: &[(&str, f32)],
+    options: &VecAttributes,
+    disabled: &str,
+    submit_fn: &str,
+    uniform_lock: Option<bool>,
+) -> String {
+    // infinite bounds are left out so that the inputs are unbounded
+    let bound = |attribute: &str, value: f32| {
+        if value.is_finite() {
+            format!(r#"{}="{}""#, attribute, value)
+        } else {
+            String::new()
+        }
+    };
+    let (min, max) = (bound("min", options.min), bound("max", options.max));
+
+    let inputs: String = components
+        .iter()
+        .map(|(name, value)| {
+            format!(
+                r#"<label class="vector-component">{name} <input type="number" {min} {max} step="{step}" value="{value}" {disabled} oninput="{submit_fn}(vectorValue(this))"></label>"#,
+                name = name,
+                value = value,
+                min = min,
+                max = max,
+                step = options.step,
+                disabled = disabled,
+                submit_fn = submit_fn,
+            )
+        })
+        .collect();
+
+    let lock = match uniform_lock {
+        Some(checked) => format!(
+            r#"<label><input class="vector-lock" type="checkbox" {checked} {disabled}> uniform</label>"#,
+            checked = if checked { "checked" } else { "" },
+            disabled = disabled,
+        ),
+        None => String::new(),
+    };
+
+    format!(
+        r#"
+            <div class="row">
+                <label class="cell text-right">{label}:</label>
+                <div class="cell vector">{inputs}{lock}</div>
+            </div>
+            "#,
+        label = label,
+        inputs = inputs,
+        lock = lock,
+    )
+}
+
+/// Parses exactly `components.len()` comma separated floats into `components`.
+pub(crate) fn parse_components(value: &str, components: &mut [f32]) -> Result<(), String> {
+    let len = components.len();
+    let mut iter = value.split(',');
+    for component in components.iter_mut() {
+        let part = iter
+            .next()
+            .ok_or_else(|| format!("expected {} components, got '{}'", len, value))?;
+        *component = part
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid component '{}' in '{}'", part, value))?;
+    }
+    if iter.next().is_some() {
+        return Err(format!("expected {} components, got '{}'", len, value));
+    }
+    Ok(())
+}
+
+impl AsHtml for Vec3 {
+    type Err = String;
+    type Options = VecAttributes;
+    const DEFAULT_OPTIONS: Self::Options = VecAttributes {
+        min: f32::NEG_INFINITY,
+        max: f32::INFINITY,
+        step: 0.1,
+    };
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let v = shared.default;
+        vector_html(
+            &shared.label,
+            &[("x", v.x), ("y", v.y), ("z", v.z)],
+            &options,
+            shared.disabled(),
+            &submit_fn,
+            None,
+        )
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut v = [0.0; 3];
+        parse_components(value, &mut v)?;
+        Ok(Vec3::from(v))
+    }
+}
+
+impl AsHtml for Vec4 {
+    type Err = String;
+    type Options = VecAttributes;
+    const DEFAULT_OPTIONS: Self::Options = VecAttributes {
+        min: f32::NEG_INFINITY,
+        max: f32::INFINITY,
+        step: 0.1,
+    };
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let v = shared.default;
+        vector_html(
+            &shared.label,
+            &[("x", v.x), ("y", v.y), ("z", v.z), ("w", v.w)],
+            &options,
+            shared.disabled(),
+            &submit_fn,
+            None,
+        )
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut v = [0.0; 4];
+        parse_components(value, &mut v)?;
+        Ok(Vec4::from(v))
+    }
+}
+
+/// The attributes of the euler angle inputs of a `Quat`, in degrees and applied to every axis.
+#[derive(Clone)]
+pub struct QuatAttributes {
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+}
+
+/// The rotation around the x, y and z axes in degrees, applied in the order y, x, z.
+fn quat_to_euler(quat: Quat) -> [f32; 3] {
+    let [x, y, z, w]: [f32; 4] = quat.into();
+
+    let pitch = (2.0 * (w * x - y * z)).max(-1.0).min(1.0).asin();
+    let yaw = (2.0 * (x * z + w * y)).atan2(1.0 - 2.0 * (x * x + y * y));
+    let roll = (2.0 * (x * y + w * z)).atan2(1.0 - 2.0 * (x * x + z * z));
+
+    [pitch.to_degrees(), yaw.to_degrees(), roll.to_degrees()]
+}
+fn euler_to_quat([pitch, yaw, roll]: [f32; 3]) -> Quat {
+    Quat::from_rotation_y(yaw.to_radians())
+        * Quat::from_rotation_x(pitch.to_radians())
+        * Quat::from_rotation_z(roll.to_radians())
+}
+
+/// Edited as euler angles in degrees.
+/// Besides the angles as `x,y,z`, it also parses the raw quaternion as `x,y,z,w`.
+impl AsHtml for Quat {
+    type Err = String;
+    type Options = QuatAttributes;
+    const DEFAULT_OPTIONS: Self::Options = QuatAttributes {
+        min: -360.0,
+        max: 360.0,
+        step: 1.0,
+    };
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let [x, y, z] = quat_to_euler(shared.default);
+        let options = VecAttributes {
+            min: options.min,
+            max: options.max,
+            step: options.step,
+        };
+        vector_html(
+            &shared.label,
+            &[("x°", x), ("y°", y), ("z°", z)],
+            &options,
+            shared.disabled(),
+            &submit_fn,
+            None,
+        )
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        if value.split(',').count() == 4 {
+            let mut raw = [0.0; 4];
+            parse_components(value, &mut raw)?;
+            let quat = Quat::from(raw);
+            let length = quat.length();
+            if !(length > 0.0 && length.is_finite()) {
+                return Err(format!("the quaternion '{}' can't be normalized", value));
+            }
+            Ok(quat.normalize())
+        } else {
+            let mut angles = [0.0; 3];
+            parse_components(value, &mut angles)?;
+            Ok(euler_to_quat(angles))
+        }
+    }
+
+    /// The euler angles are clamped to `min` and `max`, raw quaternions are taken as they are.
+    fn update_with_options(
+        &mut self,
+        value: &str,
+        options: &Self::Options,
+    ) -> Result<(), Self::Err> {
+        if value.split(',').count() == 4 {
+            return self.update(value);
+        }
+
+        let mut angles = [0.0; 3];
+        parse_components(value, &mut angles)?;
+        for angle in &mut angles {
+            *angle = angle.max(options.min).min(options.max);
+        }
+        *self = euler_to_quat(angles);
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct TransformAttributes {
+    pub translation_step: f32,
+    /// In degrees
+    pub rotation_step: f32,
+    pub scale_step: f32,
+}
+
+/// The translation, rotation and scale rows of a `Transform` or `GlobalTransform`.
+/// They are submitted as `translation:<x,y,z>`, `rotation:<x,y,z>` and `scale:<x,y,z>`.
+fn transform_html(
+    label: &str,
+    (translation, rotation, scale): (Vec3, Quat, Vec3),
+    options: &TransformAttributes,
+    disabled: &str,
+    submit_fn: &str,
+) -> String {
+    let unbounded = |step| VecAttributes {
+        min: f32::NEG_INFINITY,
+        max: f32::INFINITY,
+        step,
+    };
+    let [x, y, z] = quat_to_euler(rotation);
+    let rotation_options = VecAttributes {
+        min: -360.0,
+        max: 360.0,
+        step: options.rotation_step,
+    };
+    let is_uniform = scale.x == scale.y && scale.y == scale.z;
+
+    format!(
+        "<b>{}</b>{}{}{}",
+        label,
+        vector_html(
+            "translation",
+            &[
+                ("x", translation.x),
+                ("y", translation.y),
+                ("z", translation.z)
+            ],
+            &unbounded(options.translation_step),
+            disabled,
+            &format!("((value) => {}('translation:'+value))", submit_fn),
+            None,
+        ),
+        vector_html(
+            "rotation",
+            &[("x°", x), ("y°", y), ("z°", z)],
+            &rotation_options,
+            disabled,
+            &format!("((value) => {}('rotation:'+value))", submit_fn),
+            None,
+        ),
+        vector_html(
+            "scale",
+            &[("x", scale.x), ("y", scale.y), ("z", scale.z)],
+            &unbounded(options.scale_step),
+            disabled,
+            &format!("((value) => {}('scale:'+value))", submit_fn),
+            Some(is_uniform),
+        ),
+    )
+}
+
+impl AsHtml for Transform {
+    type Err = String;
+    type Options = TransformAttributes;
+    const DEFAULT_OPTIONS: Self::Options = TransformAttributes {
+        translation_step: 0.1,
+        rotation_step: 1.0,
+        scale_step: 0.1,
+    };
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let transform = shared.default;
+        transform_html(
+            &shared.label,
+            (transform.translation, transform.rotation, transform.scale),
+            &options,
+            shared.disabled(),
+            &submit_fn,
+        )
+    }
+
+    fn parse(_: &str) -> Result<Self, Self::Err> {
+        Err("a Transform can only be updated field by field".to_string())
+    }
+
+    fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+        let mut iter = value.splitn(2, ':');
+        match (iter.next(), iter.next()) {
+            (Some("translation"), Some(translation)) => {
+                self.translation = Vec3::parse(translation)?
+            }
+            (Some("rotation"), Some(rotation)) => self.rotation = Quat::parse(rotation)?,
+            (Some("scale"), Some(scale)) => self.scale = Vec3::parse(scale)?,
+            _ => return Err(format!("expected '<field>:<value>', got '{}'", value)),
+        }
+        Ok(())
+    }
+}
+
+/// Always readonly, since the `GlobalTransform` is computed from the `Transform` every frame.
+impl AsHtml for GlobalTransform {
+    type Err = String;
+    type Options = TransformAttributes;
+    const DEFAULT_OPTIONS: Self::Options = Transform::DEFAULT_OPTIONS;
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let transform = shared.default;
+        transform_html(
+            &shared.label,
+            (transform.translation, transform.rotation, transform.scale),
+            &options,
+            "disabled",
+            &submit_fn,
+        )
+    }
+
+    fn parse(_: &str) -> Result<Self, Self::Err> {
+        Err("a GlobalTransform can't be edited, change the Transform instead".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::{NonZeroI32, NonZeroU8};
+
+    fn shared<T>(default: T) -> SharedOptions<T> {
+        SharedOptions {
+            label: "n".into(),
+            default,
+            readonly: false,
+        }
+    }
+
+    #[test]
+    fn unsigned_nonzero_numbers_start_at_one() {
+        assert_eq!(NonZeroU8::DEFAULT_OPTIONS.min, 1);
+
+        let options = NumberAttributes {
+            min: 0,
+            max: 10,
+            ..u8::DEFAULT_OPTIONS
+        };
+        let html = NonZeroU8::as_html(
+            shared(NonZeroU8::new(5).unwrap()),
+            options.clone(),
+            "submit".to_string(),
+        );
+        assert!(html.contains(r#"min="1""#));
+
+        let mut number = NonZeroU8::new(5).unwrap();
+        assert!(number.update_with_options("0", &options).is_err());
+        number.update_with_options("7", &options).unwrap();
+        assert_eq!(number.get(), 7);
+    }
+
+    #[test]
+    fn signed_nonzero_numbers_keep_their_bounds() {
+        assert_eq!(NonZeroI32::DEFAULT_OPTIONS.min, i32::DEFAULT_OPTIONS.min);
+
+        let options = NumberAttributes {
+            min: -10,
+            max: 10,
+            ..i32::DEFAULT_OPTIONS
+        };
+        let html = NonZeroI32::as_html(
+            shared(NonZeroI32::new(5).unwrap()),
+            options.clone(),
+            "submit".to_string(),
+        );
+        assert!(html.contains(r#"min="-10""#));
+
+        let mut number = NonZeroI32::new(5).unwrap();
+        number.update_with_options("-3", &options).unwrap();
+        assert_eq!(number.get(), -3);
+        assert!(number.update_with_options("0", &options).is_err());
+        assert_eq!(number.get(), -3);
+    }
+
+    #[test]
+    fn only_bytes_are_bounded_by_default() {
+        let html = u8::as_html(shared(5), u8::DEFAULT_OPTIONS, "submit".to_string());
+        assert!(html.contains(r#"data-numscrubber="range""#));
+        let html = i8::as_html(shared(-5), i8::DEFAULT_OPTIONS, "submit".to_string());
+        assert!(html.contains(r#"data-numscrubber="range""#));
+
+        let html = u16::as_html(shared(5), u16::DEFAULT_OPTIONS, "submit".to_string());
+        assert!(html.contains(r#"data-numscrubber="drag""#));
+        let html = i32::as_html(shared(-5), i32::DEFAULT_OPTIONS, "submit".to_string());
+        assert!(html.contains(r#"data-numscrubber="drag""#));
+    }
+
+    fn assert_angles_eq(a: [f32; 3], b: [f32; 3]) {
+        for (a, b) in a.iter().zip(&b) {
+            assert!((a - b).abs() < 1e-3, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn euler_angles_round_trip() {
+        let angles = [
+            [0.0, 0.0, 0.0],
+            [30.0, 0.0, 0.0],
+            [0.0, -120.0, 0.0],
+            [0.0, 0.0, 170.0],
+            [45.0, 60.0, -30.0],
+            [-80.0, 179.0, 90.0],
+        ];
+        for &angles in &angles {
+            assert_angles_eq(quat_to_euler(euler_to_quat(angles)), angles);
+        }
+
+        let quat = Quat::from_axis_angle(Vec3::new(1.0, -2.0, 3.0).normalize(), 2.0);
+        let round_trip = euler_to_quat(quat_to_euler(quat));
+        assert!(quat.dot(round_trip).abs() > 0.9999);
+    }
+
+    #[test]
+    fn quats_are_parsed_from_angles_or_components() {
+        let quat = Quat::parse("10,20,30").unwrap();
+        assert_angles_eq(quat_to_euler(quat), [10.0, 20.0, 30.0]);
+        assert_eq!(Quat::parse("0,0,0,2"), Ok(Quat::identity()));
+        assert!(Quat::parse("0,0").is_err());
+        assert!(Quat::parse("0,0,0,0").is_err());
+
+        let mut quat = Quat::identity();
+        quat.update_with_options(
+            "100,0,0",
+            &QuatAttributes {
+                min: -60.0,
+                max: 60.0,
+                step: 1.0,
+            },
+        )
+        .unwrap();
+        assert_angles_eq(quat_to_euler(quat), [60.0, 0.0, 0.0]);
+    }
+}
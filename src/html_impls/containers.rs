@@ -0,0 +1,924 @@
+use super::escape_html;
+use crate::{as_html::AsHtml, as_html::SharedOptions};
+
+/// A checkbox toggling between `None` and `Some`, followed by the widget of the inner value.
+///
+/// Updates are encoded as `none`, `some` (which inserts `T::default()` if the value was `None`)
+/// and `some:<value>`, where `<value>` is passed to `T::update`.
+/// Checking the checkbox resets the widget to `T::default()`, see `toggleOption` in `static/script.js`.
+impl<T: AsHtml + Default> AsHtml for Option<T>
+where
+    T::Options: Clone,
+{
+    type Err = String;
+    type Options = T::Options;
+    const DEFAULT_OPTIONS: Self::Options = T::DEFAULT_OPTIONS;
+
+    fn register_header_footer(
+        types: &mut std::collections::HashSet<std::any::TypeId>,
+        header: &mut String,
+        footer: &mut String,
+    ) {
+        T::register_header_footer(types, header, footer);
+    }
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let is_some = shared.default.is_some();
+        let disabled = shared.disabled();
+        let inner = SharedOptions {
+            label: std::borrow::Cow::Borrowed("value"),
+            default: shared.default.unwrap_or_default(),
+            readonly: shared.readonly,
+        };
+        let inner_submit_fn = format!("((value) => {}('some:'+value))", submit_fn);
+        // the widget is reset to this when switching to `Some`, which inserts `T::default()`
+        let default = SharedOptions {
+            label: std::borrow::Cow::Borrowed("value"),
+            default: T::default(),
+            readonly: shared.readonly,
+        };
+        let default = T::as_html(default, options.clone(), inner_submit_fn.clone());
+
+        format!(
+            r#"
+            <div class="option">
+                <div class="row">
+                    <label for="{label}" class="cell text-right">{label}:</label>
+                    <input class="cell" type="checkbox" {checked} {disabled} oninput="{submit_fn}(toggleOption(this))" id="{label}">
+                </div>
+                <div class="option-value" {hidden}>{inner}</div>
+                <template class="option-default">{default}</template>
+            </div>
+            "#,
+            label = shared.label,
+            checked = if is_some { "checked" } else { "" },
+            disabled = disabled,
+            submit_fn = submit_fn,
+            hidden = if is_some {
+                ""
+            } else {
+                r#"style="display: none""#
+            },
+            inner = T::as_html(inner, options, inner_submit_fn),
+            default = default,
+        )
+    }
+
+    /// `None` is rendered with the widget of `T::default()` hidden
+    fn live_html(&self, options: &Self::Options) -> String {
+        match self {
+            Some(value) => value.live_html(options),
+            None => T::default().live_html(options),
+        }
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut option = None;
+        option.update(value)?;
+        Ok(option)
+    }
+
+    fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+        self.update_with_options(value, &T::DEFAULT_OPTIONS)
+    }
+
+    fn update_with_options(
+        &mut self,
+        value: &str,
+        options: &Self::Options,
+    ) -> Result<(), Self::Err> {
+        let mut iter = value.splitn(2, ':');
+        match (iter.next(), iter.next()) {
+            (Some("none"), None) => *self = None,
+            (Some("some"), None) => {
+                self.get_or_insert_with(T::default);
+            }
+            (Some("some"), Some(value)) => self
+                .get_or_insert_with(T::default)
+                .update_with_options(value, options)
+                .map_err(|e| format!("{:?}", e))?,
+            _ => {
+                return Err(format!(
+                    "expected 'none', 'some' or 'some:<value>', got '{}'",
+                    value
+                ))
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rendered and updated like `T`.
+impl<T: AsHtml> AsHtml for Box<T> {
+    type Err = T::Err;
+    type Options = T::Options;
+    const DEFAULT_OPTIONS: Self::Options = T::DEFAULT_OPTIONS;
+
+    fn register_header_footer(
+        types: &mut std::collections::HashSet<std::any::TypeId>,
+        header: &mut String,
+        footer: &mut String,
+    ) {
+        T::register_header_footer(types, header, footer);
+    }
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let inner = SharedOptions {
+            label: shared.label,
+            default: *shared.default,
+            readonly: shared.readonly,
+        };
+        T::as_html(inner, options, submit_fn)
+    }
+
+    fn live_html(&self, options: &Self::Options) -> String {
+        T::live_html(self, options)
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        T::parse(value).map(Box::new)
+    }
+
+    fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+        T::update(self, value)
+    }
+
+    fn update_with_options(
+        &mut self,
+        value: &str,
+        options: &Self::Options,
+    ) -> Result<(), Self::Err> {
+        T::update_with_options(self, value, options)
+    }
+}
+
+/// Rendered and updated like `T`, the value is cloned on update if it's shared with another `Arc`.
+impl<T: AsHtml + Clone> AsHtml for std::sync::Arc<T> {
+    type Err = T::Err;
+    type Options = T::Options;
+    const DEFAULT_OPTIONS: Self::Options = T::DEFAULT_OPTIONS;
+
+    fn register_header_footer(
+        types: &mut std::collections::HashSet<std::any::TypeId>,
+        header: &mut String,
+        footer: &mut String,
+    ) {
+        T::register_header_footer(types, header, footer);
+    }
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let inner = SharedOptions {
+            label: shared.label,
+            default: std::sync::Arc::try_unwrap(shared.default)
+                .unwrap_or_else(|arc| T::clone(&arc)),
+            readonly: shared.readonly,
+        };
+        T::as_html(inner, options, submit_fn)
+    }
+
+    fn live_html(&self, options: &Self::Options) -> String {
+        T::live_html(self, options)
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        T::parse(value).map(std::sync::Arc::new)
+    }
+
+    fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+        T::update(std::sync::Arc::make_mut(self), value)
+    }
+
+    fn update_with_options(
+        &mut self,
+        value: &str,
+        options: &Self::Options,
+    ) -> Result<(), Self::Err> {
+        T::update_with_options(std::sync::Arc::make_mut(self), value, options)
+    }
+}
+
+/// Used to include the list script only once for all `Vec`s and arrays
+struct ListScript;
+
+/// Ids of the lists on a page, so that the script can find the list an element belongs to.
+static LIST_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+fn register_list_header_footer<T: AsHtml>(
+    types: &mut std::collections::HashSet<std::any::TypeId>,
+    header: &mut String,
+    footer: &mut String,
+) {
+    if types.insert(std::any::TypeId::of::<ListScript>()) {
+        header.push_str(concat!(
+            "<script>",
+            include_str!("../../static/list_ashtml.js"),
+            "</script>"
+        ));
+    }
+    T::register_header_footer(types, header, footer);
+}
+
+/// The widgets of the `elements`, which can be dragged to reorder them.
+/// If `template` is set, elements can be added with that value and removed.
+///
+/// Elements are addressed by their current index, which the script looks up from the element's key,
+/// since the index changes when elements are reordered or removed.
+fn list_html<T: AsHtml>(
+    shared: SharedOptions<impl IntoIterator<Item = T>>,
+    options: T::Options,
+    submit_fn: &str,
+    template: Option<T>,
+) -> String
+where
+    T::Options: Clone,
+{
+    let id = format!(
+        "list_{}",
+        LIST_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    );
+    let disabled = shared.disabled();
+    let readonly = shared.readonly;
+    let removable = template.is_some();
+
+    let item = |key: &str, value: T| {
+        let element = SharedOptions {
+            label: std::borrow::Cow::Owned(format!("{}_{}", id, key)),
+            default: value,
+            readonly,
+        };
+        let element_submit_fn = format!(
+            "((value) => {}(listIndex('{}_{}')+':'+value))",
+            submit_fn, id, key
+        );
+
+        format!(
+            r#"
+            <div class="row list-item" id="{id}_{key}">
+                <div class="cell text-right list-controls">
+                    <span class="list-handle" draggable="{draggable}">&#9776;</span>
+                    <span class="list-index"></span>
+                    {remove}
+                </div>
+                <div class="cell"><div class="list-element">{element}</div></div>
+            </div>
+            "#,
+            id = id,
+            key = key,
+            draggable = !readonly,
+            remove = if removable {
+                format!(
+                    r#"<button {} onclick="{}(removeListItem(this))">&#10005;</button>"#,
+                    disabled, submit_fn
+                )
+            } else {
+                String::new()
+            },
+            element = T::as_html(element, options.clone(), element_submit_fn),
+        )
+    };
+
+    let items: String = shared
+        .default
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| item(&i.to_string(), value))
+        .collect();
+
+    let (add, template) = match template {
+        Some(value) => (
+            format!(
+                r#"<button {} onclick="{}(addListItem('{}'))">Add</button>"#,
+                disabled, submit_fn, id
+            ),
+            format!(
+                r#"<template id="{}_template">{}</template>"#,
+                id,
+                item(&format!("__key_{}__", id), value)
+            ),
+        ),
+        None => (String::new(), String::new()),
+    };
+
+    format!(
+        r#"
+        <div class="list" id="{id}">
+            <div class="row">
+                <label class="cell text-right">{label}:</label>
+                <div class="cell">{add}</div>
+            </div>
+            <div class="list-items" ondragstart="startListDrag(event)" ondragover="listDragOver(event, this)" ondrop="const move = dropListItem(event, this); if (move) {submit_fn}(move)">
+                {items}
+            </div>
+            {template}
+            <script>numberListItems(document.getElementById("{id}"))</script>
+        </div>
+        "#,
+        id = id,
+        label = shared.label,
+        add = add,
+        submit_fn = submit_fn,
+        items = items,
+        template = template,
+    )
+}
+
+/// Applies the updates shared by `Vec`s and arrays:
+/// `<index>:<value>`, which is passed to `T::update_with_options`, and `move:<from>:<to>`.
+fn update_slice<T: AsHtml>(
+    slice: &mut [T],
+    value: &str,
+    options: &T::Options,
+) -> Result<(), String> {
+    let len = slice.len();
+    let parse_index = |index: &str| {
+        index
+            .parse::<usize>()
+            .ok()
+            .filter(|&index| index < len)
+            .ok_or_else(|| format!("invalid index '{}'", index))
+    };
+
+    let mut iter = value.splitn(3, ':');
+    match (iter.next(), iter.next(), iter.next()) {
+        (Some("move"), Some(from), Some(to)) => {
+            let (from, to) = (parse_index(from)?, parse_index(to)?);
+            if from < to {
+                slice[from..=to].rotate_left(1);
+            } else {
+                slice[to..=from].rotate_right(1);
+            }
+            Ok(())
+        }
+        _ => {
+            let mut iter = value.splitn(2, ':');
+            match (iter.next(), iter.next()) {
+                (Some(index), Some(value)) => {
+                    let index = parse_index(index)?;
+                    slice[index]
+                        .update_with_options(value, options)
+                        .map_err(|e| format!("{:?}", e))
+                }
+                _ => Err(format!("expected '<index>:<value>', got '{}'", value)),
+            }
+        }
+    }
+}
+
+/// Updates are encoded as `<index>:<value>`, `push`, `remove:<index>` and `move:<from>:<to>`.
+impl<T: AsHtml + Default> AsHtml for Vec<T>
+where
+    T::Options: Clone,
+{
+    type Err = String;
+    type Options = T::Options;
+    const DEFAULT_OPTIONS: Self::Options = T::DEFAULT_OPTIONS;
+
+    fn register_header_footer(
+        types: &mut std::collections::HashSet<std::any::TypeId>,
+        header: &mut String,
+        footer: &mut String,
+    ) {
+        register_list_header_footer::<T>(types, header, footer);
+    }
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        list_html(shared, options, &submit_fn, Some(T::default()))
+    }
+
+    fn live_html(&self, options: &Self::Options) -> String {
+        self.iter()
+            .map(|element| element.live_html(options))
+            .collect()
+    }
+
+    fn parse(_: &str) -> Result<Self, Self::Err> {
+        Err("a Vec can only be updated element by element".to_string())
+    }
+
+    fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+        self.update_with_options(value, &T::DEFAULT_OPTIONS)
+    }
+
+    fn update_with_options(
+        &mut self,
+        value: &str,
+        options: &Self::Options,
+    ) -> Result<(), Self::Err> {
+        if value == "push" {
+            self.push(T::default());
+            return Ok(());
+        }
+        if let Some(index) = value.strip_prefix("remove:") {
+            return match index.parse::<usize>() {
+                Ok(index) if index < self.len() => {
+                    self.remove(index);
+                    Ok(())
+                }
+                _ => Err(format!("invalid index '{}'", index)),
+            };
+        }
+        update_slice(self, value, options)
+    }
+}
+
+/// Like `Vec<T>`, but without adding and removing elements.
+impl<T: AsHtml, const N: usize> AsHtml for [T; N]
+where
+    T::Options: Clone,
+{
+    type Err = String;
+    type Options = T::Options;
+    const DEFAULT_OPTIONS: Self::Options = T::DEFAULT_OPTIONS;
+
+    fn register_header_footer(
+        types: &mut std::collections::HashSet<std::any::TypeId>,
+        header: &mut String,
+        footer: &mut String,
+    ) {
+        register_list_header_footer::<T>(types, header, footer);
+    }
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        list_html(shared, options, &submit_fn, None)
+    }
+
+    fn live_html(&self, options: &Self::Options) -> String {
+        self.iter()
+            .map(|element| element.live_html(options))
+            .collect()
+    }
+
+    fn parse(_: &str) -> Result<Self, Self::Err> {
+        Err("an array can only be updated element by element".to_string())
+    }
+
+    fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+        update_slice(self, value, &T::DEFAULT_OPTIONS)
+    }
+
+    fn update_with_options(
+        &mut self,
+        value: &str,
+        options: &Self::Options,
+    ) -> Result<(), Self::Err> {
+        update_slice(self, value, options)
+    }
+}
+
+/// Percent-encodes `key` like `encodeMapKey` in `list_ashtml.js`,
+/// so that it can be used in the submit functions and doesn't contain the `:` separating it from the value.
+fn encode_key(key: &str) -> String {
+    let mut encoded = String::with_capacity(key.len());
+    for byte in key.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => encoded.push(byte as char),
+            b'-' | b'_' | b'.' | b'!' | b'~' | b'*' | b'(' | b')' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+/// Decodes a percent-encoded string like `decodeURIComponent`, e.g. a key encoded by [`encode_key`]
+pub(crate) fn percent_decode(encoded: &str) -> Result<String, String> {
+    let invalid = || format!("invalid percent-encoding '{}'", encoded);
+
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut iter = encoded.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hex = [
+                iter.next().ok_or_else(invalid)?,
+                iter.next().ok_or_else(invalid)?,
+            ];
+            let hex = std::str::from_utf8(&hex).map_err(|_| invalid())?;
+            bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+/// A pattern for the keys of the standard types which can't be parsed from any string,
+/// in the form they are displayed in, which is checked in the browser before an entry is inserted.
+/// Keys of other types aren't checked there, and neither is the range of integers,
+/// they are rejected by the server instead.
+fn key_pattern<K: 'static>() -> Option<&'static str> {
+    use std::any::TypeId;
+
+    let is_any = |types: &[TypeId]| types.contains(&TypeId::of::<K>());
+    let unsigned = [
+        TypeId::of::<u8>(),
+        TypeId::of::<u16>(),
+        TypeId::of::<u32>(),
+        TypeId::of::<u64>(),
+        TypeId::of::<u128>(),
+        TypeId::of::<usize>(),
+    ];
+    let signed = [
+        TypeId::of::<i8>(),
+        TypeId::of::<i16>(),
+        TypeId::of::<i32>(),
+        TypeId::of::<i64>(),
+        TypeId::of::<i128>(),
+        TypeId::of::<isize>(),
+    ];
+
+    if is_any(&unsigned) {
+        Some("0|[1-9][0-9]*")
+    } else if is_any(&signed) {
+        Some("0|-?[1-9][0-9]*")
+    } else if is_any(&[TypeId::of::<bool>()]) {
+        Some("true|false")
+    } else if is_any(&[TypeId::of::<char>()]) {
+        Some(".")
+    } else {
+        None
+    }
+}
+
+/// The widgets of the values of a map, labeled with their key, and inputs for inserting and removing entries.
+/// The entries are ordered by their keys.
+fn map_html<K: std::fmt::Display + Ord + 'static, V: AsHtml>(
+    shared: SharedOptions<impl IntoIterator<Item = (K, V)>>,
+    options: V::Options,
+    submit_fn: &str,
+) -> String
+where
+    V: Default,
+    V::Options: Clone,
+{
+    let id = format!(
+        "list_{}",
+        LIST_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    );
+    let disabled = shared.disabled();
+    let readonly = shared.readonly;
+
+    let item = |key: &str, encoded_key: &str, value: V| {
+        let element = SharedOptions {
+            label: std::borrow::Cow::Owned(format!("{}_{}", id, encoded_key)),
+            default: value,
+            readonly,
+        };
+        let element_submit_fn = format!("((value) => {}('set:{}:'+value))", submit_fn, encoded_key);
+
+        format!(
+            r#"
+            <div class="row list-item" data-key="{encoded_key}">
+                <div class="cell text-right list-controls">
+                    <span class="map-key">{key}</span>
+                    <button {disabled} onclick="{submit_fn}(removeMapEntry(this))">&#10005;</button>
+                </div>
+                <div class="cell"><div class="list-element">{element}</div></div>
+            </div>
+            "#,
+            encoded_key = encoded_key,
+            key = escape_html(key),
+            disabled = disabled,
+            submit_fn = submit_fn,
+            element = V::as_html(element, options.clone(), element_submit_fn),
+        )
+    };
+
+    let mut entries: Vec<(K, V)> = shared.default.into_iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let items: String = entries
+        .into_iter()
+        .map(|(key, value)| {
+            let key = key.to_string();
+            item(&key, &encode_key(&key), value)
+        })
+        .collect();
+    let placeholder = format!("__key_{}__", id);
+    let pattern = match key_pattern::<K>() {
+        Some(pattern) => format!(r#" data-pattern="{}""#, escape_html(pattern)),
+        None => String::new(),
+    };
+
+    format!(
+        r#"
+        <div class="list" id="{id}">
+            <div class="row">
+                <label class="cell text-right">{label}:</label>
+                <div class="cell">
+                    <input type="text" placeholder="key"{pattern} {disabled} oninput="checkPattern(this)">
+                    <button {disabled} onclick="addMapEntry('{id}', this, {submit_fn})">Insert</button>
+                </div>
+            </div>
+            <div class="list-items">
+                {items}
+            </div>
+            <template id="{id}_template">{template}</template>
+        </div>
+        "#,
+        id = id,
+        label = shared.label,
+        pattern = pattern,
+        disabled = disabled,
+        submit_fn = submit_fn,
+        items = items,
+        template = item("", &placeholder, V::default()),
+    )
+}
+
+enum MapUpdate<'a> {
+    Set(&'a str),
+    Insert,
+    Remove,
+}
+
+/// Parses `set:<key>:<value>`, `insert:<key>` or `remove:<key>`,
+/// returning the key as it was entered besides the parsed key.
+fn parse_map_update<K: std::str::FromStr>(
+    value: &str,
+) -> Result<(K, String, MapUpdate<'_>), String> {
+    let mut iter = value.splitn(3, ':');
+    let (update, key) = match (iter.next(), iter.next(), iter.next()) {
+        (Some("set"), Some(key), Some(value)) => (MapUpdate::Set(value), key),
+        (Some("insert"), Some(key), None) => (MapUpdate::Insert, key),
+        (Some("remove"), Some(key), None) => (MapUpdate::Remove, key),
+        _ => {
+            return Err(format!(
+                "expected 'set:<key>:<value>', 'insert:<key>' or 'remove:<key>', got '{}'",
+                value
+            ))
+        }
+    };
+
+    let entered = percent_decode(key)?;
+    let key = entered
+        .parse()
+        .map_err(|_| format!("invalid key '{}'", entered))?;
+    Ok((key, entered, update))
+}
+
+macro_rules! impl_ashtml_for_map {
+    ($map:ident where K: $($key_bounds:path),+) => {
+        /// The entries are shown ordered by their keys.
+        /// Updates are encoded as `set:<key>:<value>`, `insert:<key>` and `remove:<key>`, with the key percent-encoded.
+        /// Inserting fails if the key already exists or isn't in the form it is displayed in, e.g. `01` instead of `1`.
+        impl<K, V> AsHtml for std::collections::$map<K, V>
+        where
+            K: std::str::FromStr + std::fmt::Display $(+ $key_bounds)* + 'static,
+            V: AsHtml + Default,
+            V::Options: Clone,
+        {
+            type Err = String;
+            type Options = V::Options;
+            const DEFAULT_OPTIONS: Self::Options = V::DEFAULT_OPTIONS;
+
+            fn register_header_footer(
+                types: &mut std::collections::HashSet<std::any::TypeId>,
+                header: &mut String,
+                footer: &mut String,
+            ) {
+                register_list_header_footer::<V>(types, header, footer);
+            }
+
+            fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+                map_html(shared, options, &submit_fn)
+            }
+
+            /// In the order of the keys, like `map_html`
+            fn live_html(&self, options: &Self::Options) -> String {
+                let mut entries: Vec<(&K, &V)> = self.iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                entries.into_iter().map(|(_, value)| value.live_html(options)).collect()
+            }
+
+            fn parse(_: &str) -> Result<Self, Self::Err> {
+                Err("a map can only be updated entry by entry".to_string())
+            }
+
+            fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+                self.update_with_options(value, &V::DEFAULT_OPTIONS)
+            }
+
+            fn update_with_options(&mut self, value: &str, options: &Self::Options) -> Result<(), Self::Err> {
+                match parse_map_update::<K>(value)? {
+                    (key, _, MapUpdate::Set(value)) => {
+                        let entry = self.get_mut(&key).ok_or_else(|| format!("no entry for '{}'", key))?;
+                        entry.update_with_options(value, options).map_err(|e| format!("{:?}", e))?;
+                    }
+                    (key, entered, MapUpdate::Insert) => {
+                        // the browser shows the key as it was entered
+                        if key.to_string() != entered {
+                            return Err(format!("the key '{}' would be shown as '{}'", entered, key));
+                        }
+                        if self.contains_key(&key) {
+                            return Err(format!("'{}' already exists", key));
+                        }
+                        self.insert(key, V::default());
+                    }
+                    (key, _, MapUpdate::Remove) => {
+                        self.remove(&key);
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+// `Ord` is needed to show the entries in a stable order
+impl_ashtml_for_map!(HashMap where K: std::hash::Hash, Eq, Ord);
+impl_ashtml_for_map!(BTreeMap where K: Ord);
+
+/// Tuples are rendered like derived tuple structs, the elements are updated using `#<index>:<value>`.
+macro_rules! impl_ashtml_for_tuple {
+    ($($T:ident $i:tt),+) => {
+        impl<$($T: AsHtml),+> AsHtml for ($($T,)+) {
+            type Err = String;
+            type Options = ();
+            const DEFAULT_OPTIONS: Self::Options = ();
+
+            fn register_header_footer(
+                types: &mut std::collections::HashSet<std::any::TypeId>,
+                header: &mut String,
+                footer: &mut String,
+            ) {
+                $($T::register_header_footer(types, header, footer);)+
+            }
+
+            fn as_html(shared: SharedOptions<Self>, (): Self::Options, submit_fn: String) -> String {
+                let readonly = shared.readonly;
+                let value = shared.default;
+
+                let mut html = format!("<b>{}</b>", shared.label);
+                $(
+                    let element = SharedOptions {
+                        label: std::borrow::Cow::Borrowed(concat!("#", stringify!($i))),
+                        default: value.$i,
+                        readonly,
+                    };
+                    let element_submit_fn = format!("((value) => {}('#{}:'+value))", submit_fn, $i);
+                    html.push_str(&$T::as_html(element, $T::DEFAULT_OPTIONS, element_submit_fn));
+                )+
+                html.push_str("<br />");
+                html
+            }
+
+            fn live_html(&self, (): &Self::Options) -> String {
+                let mut html = String::new();
+                $(html.push_str(&self.$i.live_html(&$T::DEFAULT_OPTIONS));)+
+                html
+            }
+
+            fn parse(_: &str) -> Result<Self, Self::Err> {
+                Err("a tuple can only be updated element by element".to_string())
+            }
+
+            fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+                let mut iter = value.splitn(2, ':');
+                let (index, value) = iter.next().zip(iter.next())
+                    .ok_or_else(|| format!("expected '#<index>:<value>', got '{}'", value))?;
+
+                match index {
+                    $(concat!("#", stringify!($i)) => self.$i.update_with_options(value, &$T::DEFAULT_OPTIONS).map_err(|e| format!("{:?}", e))?,)+
+                    other => return Err(format!("unexpected element '{}'", other)),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_ashtml_for_tuple!(A 0);
+impl_ashtml_for_tuple!(A 0, B 1);
+impl_ashtml_for_tuple!(A 0, B 1, C 2);
+impl_ashtml_for_tuple!(A 0, B 1, C 2, D 3);
+impl_ashtml_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_ashtml_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_ashtml_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_ashtml_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn keys_are_percent_encoded() {
+        assert_eq!(encode_key("a:b"), "a%3Ab");
+        assert_eq!(encode_key("it's"), "it%27s");
+        assert_eq!(encode_key("ä"), "%C3%A4");
+        assert_eq!(encode_key("a-b_c.d"), "a-b_c.d");
+
+        for key in &["a:b", "it's", "ä", "<\"&>", "", "100%"] {
+            assert_eq!(percent_decode(&encode_key(key)).unwrap(), *key);
+        }
+        assert!(percent_decode("%").is_err());
+        assert!(percent_decode("%4").is_err());
+        assert!(percent_decode("%zz").is_err());
+        assert!(percent_decode("%FF").is_err());
+    }
+
+    #[test]
+    fn list_elements_are_moved_and_removed() {
+        let mut list = vec![0, 1, 2, 3];
+        list.update("move:0:2").unwrap();
+        assert_eq!(list, [1, 2, 0, 3]);
+        list.update("move:3:0").unwrap();
+        assert_eq!(list, [3, 1, 2, 0]);
+        list.update("remove:1").unwrap();
+        assert_eq!(list, [3, 2, 0]);
+        list.update("push").unwrap();
+        list.update("3:7").unwrap();
+        assert_eq!(list, [3, 2, 0, 7]);
+
+        assert!(list.update("move:0:4").is_err());
+        assert!(list.update("remove:4").is_err());
+        assert!(list.update("4:1").is_err());
+        assert_eq!(list, [3, 2, 0, 7]);
+
+        let mut array = [0, 1, 2];
+        array.update("move:2:0").unwrap();
+        assert_eq!(array, [2, 0, 1]);
+    }
+
+    #[test]
+    fn options_are_reset_to_the_default_when_enabled() {
+        let shared = SharedOptions {
+            label: "option".into(),
+            default: Some(String::from("value")),
+            readonly: false,
+        };
+        let html = Option::<String>::as_html(shared, String::DEFAULT_OPTIONS, "submit".into());
+        let template = &html[html.find("<template").unwrap()..];
+        assert!(html.contains(r#"value="value""#));
+        assert!(!template.contains(r#"value="value""#));
+
+        let mut option = Some(String::from("value"));
+        option.update("none").unwrap();
+        option.update("some").unwrap();
+        assert_eq!(option, Some(String::new()));
+    }
+
+    #[test]
+    fn map_entries_are_updated_by_key() {
+        let mut map = HashMap::<String, i32>::new();
+        map.update("insert:a%3Ab").unwrap();
+        map.update("set:a%3Ab:5").unwrap();
+        assert_eq!(map["a:b"], 5);
+        map.update("remove:a%3Ab").unwrap();
+        assert!(map.is_empty());
+        assert!(map.update("set:x:1").is_err());
+
+        let mut map = BTreeMap::<u8, i32>::new();
+        assert!(map.update("insert:x").is_err());
+        assert!(map.update("insert:256").is_err());
+        assert!(map.update("insert:01").is_err());
+        assert!(map.update("insert:+1").is_err());
+        assert!(map.is_empty());
+
+        map.update("insert:1").unwrap();
+        map.update("set:1:5").unwrap();
+        assert!(map.update("insert:1").is_err());
+        assert_eq!(map[&1], 5);
+    }
+
+    #[test]
+    fn elements_are_updated_with_the_options_of_the_container() {
+        let options = crate::as_html::StringAttributes {
+            max_length: 3,
+            ..String::DEFAULT_OPTIONS
+        };
+
+        let mut list = vec![String::new()];
+        assert!(list.update_with_options("0:abcd", &options).is_err());
+        list.update_with_options("0:abc", &options).unwrap();
+        assert_eq!(list, ["abc"]);
+
+        let mut option = Some(String::new());
+        assert!(option.update_with_options("some:abcd", &options).is_err());
+
+        let mut map = BTreeMap::<u8, String>::new();
+        map.update("insert:1").unwrap();
+        assert!(map.update_with_options("set:1:abcd", &options).is_err());
+        assert_eq!(map[&1], "");
+    }
+
+    #[test]
+    fn map_entries_are_ordered_by_key() {
+        let map: HashMap<u32, bool> = [(10, true), (2, true), (1, true)].iter().copied().collect();
+        let shared = SharedOptions {
+            label: "map".into(),
+            default: map,
+            readonly: false,
+        };
+        let html = HashMap::as_html(shared, bool::DEFAULT_OPTIONS, "submit".to_string());
+
+        let position = |key: &str| html.find(&format!(r#"data-key="{}""#, key)).unwrap();
+        assert!(position("1") < position("2"));
+        assert!(position("2") < position("10"));
+    }
+
+    #[test]
+    fn only_keys_of_standard_types_are_checked() {
+        assert_eq!(key_pattern::<u8>(), Some("0|[1-9][0-9]*"));
+        assert_eq!(key_pattern::<i64>(), Some("0|-?[1-9][0-9]*"));
+        assert_eq!(key_pattern::<String>(), None);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{as_html::AsHtml, as_html::SharedOptions};
+
+/// Enums whose variants don't have fields, which can be used as flags in a `HashSet` or `BTreeSet`.
+///
+/// This is implemented by `#[derive(AsHtml)]` for such enums.
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use bevy_contrib_inspector::{AsHtml, Inspectable};
+///
+/// #[derive(AsHtml, Clone, PartialEq, Eq, Hash)]
+/// enum Layer {
+///     Ground,
+///     Player,
+///     Enemy,
+/// }
+///
+/// #[derive(Inspectable, Default)]
+/// struct Data {
+///     collides_with: HashSet<Layer>,
+/// }
+/// ```
+pub trait UnitEnum: Sized + 'static {
+    /// The label and value of every variant
+    const VARIANTS: &'static [(&'static str, Self)];
+}
+
+/// A group of checkboxes, one for each of the `flags` with its label and whether it is set.
+///
+/// Toggling a checkbox submits `insert:<index>` or `remove:<index>`, see [`parse_flag_update`].
+#[doc(hidden)]
+pub fn flags_html(label: &str, flags: &[(&str, bool)], disabled: &str, submit_fn: &str) -> String {
+    let checkboxes: String = flags
+        .iter()
+        .enumerate()
+        .map(|(i, (flag, set))| {
+            format!(
+                r#"
+                <label>
+                    <input type="checkbox" {checked} {disabled} oninput="{submit_fn}((this.checked ? 'insert:' : 'remove:') + {i})"/>
+                    <span>{flag}</span>
+                </label>
+                "#,
+                checked = if *set { "checked" } else { "" },
+                disabled = disabled,
+                submit_fn = submit_fn,
+                i = i,
+                flag = flag,
+            )
+        })
+        .collect();
+
+    format!(
+        r#"
+        <div class="row">
+            <label class="cell text-right">{}:</label>
+            <div class="cell flags">{}</div>
+        </div>
+        "#,
+        label, checkboxes
+    )
+}
+
+/// Parses `insert:<index>` or `remove:<index>` into whether the flag should be set and its index
+#[doc(hidden)]
+pub fn parse_flag_update(value: &str, count: usize) -> Result<(bool, usize), String> {
+    let mut iter = value.splitn(2, ':');
+    let insert = match iter.next() {
+        Some("insert") => true,
+        Some("remove") => false,
+        _ => {
+            return Err(format!(
+                "expected 'insert:<index>' or 'remove:<index>', got '{}'",
+                value
+            ))
+        }
+    };
+
+    match iter.next().map(str::parse::<usize>) {
+        Some(Ok(index)) if index < count => Ok((insert, index)),
+        _ => Err(format!("invalid flag in '{}'", value)),
+    }
+}
+
+macro_rules! impl_ashtml_for_set {
+    ($set:ident where E: $($bounds:path),+) => {
+        /// Rendered as a checkbox for every variant of `E`.
+        impl<E> AsHtml for std::collections::$set<E>
+        where
+            E: UnitEnum + Clone $(+ $bounds)*,
+        {
+            type Err = String;
+            type Options = ();
+            const DEFAULT_OPTIONS: Self::Options = ();
+
+            fn as_html(shared: SharedOptions<Self>, (): Self::Options, submit_fn: String) -> String {
+                let flags: Vec<(&str, bool)> = E::VARIANTS
+                    .iter()
+                    .map(|(label, variant)| (*label, shared.default.contains(variant)))
+                    .collect();
+                flags_html(&shared.label, &flags, shared.disabled(), &submit_fn)
+            }
+
+            fn parse(_: &str) -> Result<Self, Self::Err> {
+                Err("a set of flags can only be updated flag by flag".to_string())
+            }
+
+            fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+                let (insert, index) = parse_flag_update(value, E::VARIANTS.len())?;
+                let variant = &E::VARIANTS[index].1;
+                if insert {
+                    self.insert(variant.clone());
+                } else {
+                    self.remove(variant);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_ashtml_for_set!(HashSet where E: std::hash::Hash, Eq);
+impl_ashtml_for_set!(BTreeSet where E: Ord);
+
+/// Implements [`AsHtml`](crate::AsHtml) for a type generated by the `bitflags!` macro,
+/// which is rendered as a checkbox for each of the listed flags.
+///
+/// ```rust
+/// # #[macro_use] extern crate bitflags;
+/// use bevy_contrib_inspector::{impl_as_html_for_bitflags, Inspectable};
+///
+/// bitflags! {
+///     #[derive(Default)]
+///     struct Abilities: u8 {
+///         const JUMP = 0b001;
+///         const DASH = 0b010;
+///         const SWIM = 0b100;
+///     }
+/// }
+/// impl_as_html_for_bitflags!(Abilities { JUMP, DASH, SWIM });
+///
+/// #[derive(Inspectable, Default)]
+/// struct Data {
+///     abilities: Abilities,
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! impl_as_html_for_bitflags {
+    ($flags:ty { $($flag:ident),+ $(,)? }) => {
+        impl $crate::AsHtml for $flags {
+            type Err = String;
+            type Options = ();
+            const DEFAULT_OPTIONS: Self::Options = ();
+
+            fn as_html(
+                shared: $crate::as_html::SharedOptions<Self>,
+                (): Self::Options,
+                submit_fn: String,
+            ) -> String {
+                let flags = [$((stringify!($flag), shared.default.contains(<$flags>::$flag))),+];
+                $crate::as_html::flags_html(&shared.label, &flags, shared.disabled(), &submit_fn)
+            }
+
+            fn parse(_: &str) -> Result<Self, Self::Err> {
+                Err("flags can only be updated flag by flag".to_string())
+            }
+
+            fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+                let flags = [$(<$flags>::$flag),+];
+                let (insert, index) = $crate::as_html::parse_flag_update(value, flags.len())?;
+                self.set(flags[index], insert);
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    enum Layer {
+        Ground,
+        Player,
+    }
+    impl UnitEnum for Layer {
+        const VARIANTS: &'static [(&'static str, Self)] =
+            &[("Ground", Layer::Ground), ("Player", Layer::Player)];
+    }
+
+    bitflags::bitflags! {
+        #[derive(Default)]
+        struct Abilities: u8 {
+            const JUMP = 0b01;
+            const DASH = 0b10;
+        }
+    }
+    impl_as_html_for_bitflags!(Abilities { JUMP, DASH });
+
+    #[test]
+    fn flag_updates_are_parsed() {
+        assert_eq!(parse_flag_update("insert:0", 2), Ok((true, 0)));
+        assert_eq!(parse_flag_update("remove:1", 2), Ok((false, 1)));
+
+        assert!(parse_flag_update("insert:2", 2).is_err());
+        assert!(parse_flag_update("insert:-1", 2).is_err());
+        assert!(parse_flag_update("insert", 2).is_err());
+        assert!(parse_flag_update("toggle:0", 2).is_err());
+    }
+
+    #[test]
+    fn sets_are_updated_flag_by_flag() {
+        let mut set = HashSet::new();
+        set.update("insert:1").unwrap();
+        set.update("insert:0").unwrap();
+        set.update("remove:1").unwrap();
+        assert_eq!(set, [Layer::Ground].iter().cloned().collect());
+
+        let mut set = BTreeSet::<Layer>::new();
+        assert!(set.update("insert:2").is_err());
+        assert!(BTreeSet::<Layer>::parse("Ground").is_err());
+    }
+
+    #[test]
+    fn bitflags_are_updated_flag_by_flag() {
+        let mut abilities = Abilities::default();
+        abilities.update("insert:1").unwrap();
+        assert_eq!(abilities, Abilities::DASH);
+        abilities.update("insert:0").unwrap();
+        abilities.update("remove:1").unwrap();
+        assert_eq!(abilities, Abilities::JUMP);
+        assert!(abilities.update("insert:2").is_err());
+    }
+}
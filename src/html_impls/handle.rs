@@ -0,0 +1,51 @@
+use bevy::asset::Asset;
+use bevy::prelude::*;
+
+use crate::{as_html::AsHtml, as_html::SharedOptions};
+
+static HANDLE_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// A select listing the loaded assets of the same type, see `loadHandles` in `static/script.js`.
+///
+/// The page is rendered without access to the app, so the select starts out with only the current handle
+/// and is filled from `GET /handles/<type>`, which also knows the paths of the assets.
+/// This needs the asset type to be registered using `register_inspectable_handle`.
+impl<T: Asset> AsHtml for Handle<T> {
+    type Err = String;
+    type Options = ();
+    const DEFAULT_OPTIONS: Self::Options = ();
+
+    fn as_html(shared: SharedOptions<Self>, (): Self::Options, submit_fn: String) -> String {
+        let id = format!(
+            "handle_{}",
+            HANDLE_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+        );
+        let key = crate::assets::handle_key(shared.default.id);
+
+        format!(
+            r#"
+            <div class="row">
+                <label for="{id}" class="cell text-right">{label}:</label>
+                <select class="cell handle" data-handle-type="{ty}" {disabled} onfocus="loadHandles(this, true)" onchange="{submit_fn}(this.value)" id="{id}">
+                    <option value="{key}" selected>{key}</option>
+                </select>
+            </div>
+            "#,
+            id = id,
+            label = shared.label,
+            ty = super::escape_html(std::any::type_name::<T>()),
+            disabled = shared.disabled(),
+            submit_fn = submit_fn,
+            key = key,
+        )
+    }
+
+    fn footer() -> &'static str {
+        "<script>initHandleSelects(document)</script>"
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        crate::handles::resolve_handle(value)
+            .ok_or_else(|| format!("no loaded asset of this type has the key '{}'", value))
+    }
+}
@@ -0,0 +1,126 @@
+use std::path::PathBuf;
+
+use super::escape_html;
+use crate::{as_html::AsHtml, as_html::SharedOptions};
+
+/// The attributes of `PathBuf` and [`AssetFile`]
+#[derive(Clone)]
+pub struct PathAttributes {
+    /// Comma separated extensions of the files suggested by the picker, e.g. `"png,jpg"`.
+    /// All files are suggested if it's empty.
+    pub extensions: &'static str,
+}
+
+/// A path relative to the asset folder, like the ones passed to `AssetServer::load`.
+///
+/// It is edited using the same picker as `PathBuf`,
+/// which suggests the files in the asset folder.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_contrib_inspector::{AssetFile, Inspectable};
+///
+/// #[derive(Inspectable, Default)]
+/// struct Data {
+///     #[inspectable(extensions = "png")]
+///     texture: AssetFile,
+/// }
+///
+/// fn reload_texture(data: ChangedRes<Data>, asset_server: Res<AssetServer>) {
+///     let texture: Handle<Texture> = asset_server.load(data.texture.as_str());
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AssetFile(pub String);
+
+impl AssetFile {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for AssetFile {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+static PATH_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// A text input suggesting the files in the asset folder, see `loadAssetFiles` in `static/script.js`.
+///
+/// The path is only submitted after picking a file or leaving the input,
+/// so that assets aren't loaded for every typed character.
+fn path_html(
+    label: &str,
+    path: &str,
+    options: &PathAttributes,
+    disabled: &str,
+    submit_fn: &str,
+) -> String {
+    let id = format!(
+        "path_{}",
+        PATH_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    );
+
+    format!(
+        r#"
+            <div class="row">
+                <label for="{id}" class="cell text-right">{label}:</label>
+                <div class="cell path-picker" data-extensions="{extensions}">
+                    <input type="text" list="{id}_files" value="{path}" placeholder="search assets" {disabled} onfocus="loadAssetFiles(this)" onchange="{submit_fn}(this.value)" id="{id}">
+                    <datalist id="{id}_files"></datalist>
+                </div>
+            </div>
+            "#,
+        id = id,
+        label = label,
+        extensions = escape_html(options.extensions),
+        path = escape_html(path),
+        disabled = disabled,
+        submit_fn = submit_fn,
+    )
+}
+
+impl AsHtml for PathBuf {
+    type Err = std::convert::Infallible;
+    type Options = PathAttributes;
+    const DEFAULT_OPTIONS: Self::Options = PathAttributes { extensions: "" };
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        path_html(
+            &shared.label,
+            &shared.default.to_string_lossy(),
+            &options,
+            shared.disabled(),
+            &submit_fn,
+        )
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(PathBuf::from(value))
+    }
+}
+
+impl AsHtml for AssetFile {
+    type Err = std::convert::Infallible;
+    type Options = PathAttributes;
+    const DEFAULT_OPTIONS: Self::Options = PathBuf::DEFAULT_OPTIONS;
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        path_html(
+            &shared.label,
+            &shared.default.0,
+            &options,
+            shared.disabled(),
+            &submit_fn,
+        )
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(AssetFile(value.to_string()))
+    }
+}
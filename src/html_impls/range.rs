@@ -0,0 +1,303 @@
+use std::ops::{Range, RangeInclusive};
+
+use super::{finite_attribute, NumberAttributes};
+use crate::{as_html::AsHtml, as_html::SharedOptions};
+
+/// Used to include the range slider script only once for all ranges
+struct RangeScript;
+
+/// Two sliders between `min` and `max` which can't be dragged past each other if the range is bounded,
+/// otherwise two number inputs which are changed by dragging them. See `static/range_ashtml.js`.
+///
+/// The ends are submitted as `start:<value>` and `end:<value>`.
+#[allow(clippy::too_many_arguments)]
+fn range_html(
+    label: &str,
+    start: &str,
+    end: &str,
+    separator: &str,
+    bounds: Option<(f64, f64)>,
+    step: &str,
+    disabled: &str,
+    submit_fn: &str,
+) -> String {
+    let (min, max) = bounds.unwrap_or((f64::NEG_INFINITY, f64::INFINITY));
+    let number = |end: &str, value: &str| {
+        format!(
+            r#"<input type="number" data-numscrubber="drag" class="range-{end}" {min} {max} step="{step}" value="{value}" {disabled} oninput="{submit_fn}('{end}:'+this.value)">"#,
+            end = end,
+            min = finite_attribute("min", min, min),
+            max = finite_attribute("max", max, max),
+            step = step,
+            value = value,
+            disabled = disabled,
+            submit_fn = submit_fn,
+        )
+    };
+
+    let sliders = match bounds {
+        Some((min, max)) => {
+            let slider = |value: &str| {
+                format!(
+                    r#"<input type="range" min="{}" max="{}" step="{}" value="{}" {}>"#,
+                    min, max, step, value, disabled
+                )
+            };
+            format!(
+                r#"<span class="range-track">{}{}</span>"#,
+                slider(start),
+                slider(end)
+            )
+        }
+        None => String::new(),
+    };
+
+    format!(
+        r#"
+            <div class="row">
+                <label class="cell text-right">{label}:</label>
+                <div class="cell range-slider">{start}{separator}{end}{sliders}</div>
+            </div>
+            "#,
+        label = label,
+        start = number("start", start),
+        separator = separator,
+        end = number("end", end),
+        sliders = sliders,
+    )
+}
+
+/// Parses `start:<value>` or `end:<value>`
+fn parse_range_update<T: AsHtml>(value: &str) -> Result<(bool, T), String> {
+    let mut iter = value.splitn(2, ':');
+    let is_start = match iter.next() {
+        Some("start") => true,
+        Some("end") => false,
+        _ => {
+            return Err(format!(
+                "expected 'start:<value>' or 'end:<value>', got '{}'",
+                value
+            ))
+        }
+    };
+
+    let value = iter.next().unwrap_or_default();
+    let value = T::parse(value).map_err(|e| format!("{:?}", e))?;
+    Ok((is_start, value))
+}
+
+/// Applies `start:<value>` or `end:<value>` to the ends of a range, clamping the value between `min` and `max`.
+///
+/// Fails without changing the range if the start would be after the end.
+fn update_range<T: AsHtml + PartialOrd + Copy>(
+    (start, end): (T, T),
+    value: &str,
+    options: &NumberAttributes<T>,
+) -> Result<(T, T), String> {
+    let (is_start, value) = parse_range_update::<T>(value)?;
+    let value = if value < options.min {
+        options.min
+    } else if value > options.max {
+        options.max
+    } else {
+        value
+    };
+
+    let (start, end) = if is_start {
+        (value, end)
+    } else {
+        (start, value)
+    };
+    match start.partial_cmp(&end) {
+        Some(std::cmp::Ordering::Less) | Some(std::cmp::Ordering::Equal) => Ok((start, end)),
+        _ => Err("the start of the range can't be after its end".to_string()),
+    }
+}
+
+macro_rules! impl_ashtml_for_range {
+    ($($ty:ty),+) => {$(
+        /// Bounded ranges are edited with a slider with two handles,
+        /// the `scale` and `angle` of the `NumberAttributes` are ignored.
+        /// The ends are clamped between `min` and `max`, and an update moving the start after the end is rejected.
+        impl AsHtml for Range<$ty> {
+            type Err = String;
+            type Options = NumberAttributes<$ty>;
+            const DEFAULT_OPTIONS: Self::Options = <$ty as AsHtml>::DEFAULT_OPTIONS;
+
+            fn header() -> &'static str {
+                concat!("<script>", include_str!("../../static/range_ashtml.js"), "</script>")
+            }
+            fn footer() -> &'static str {
+                "<script>initRangeSliders(document)</script>"
+            }
+
+            fn register_header_footer(
+                types: &mut std::collections::HashSet<std::any::TypeId>,
+                header: &mut String,
+                footer: &mut String,
+            ) {
+                <$ty>::register_header_footer(types, header, footer);
+                if types.insert(std::any::TypeId::of::<RangeScript>()) {
+                    header.push_str(Self::header());
+                    footer.push_str(Self::footer());
+                }
+            }
+
+            fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+                let (min, max) = (options.min as f64, options.max as f64);
+                let bounded = is_bounded!($ty, options);
+
+                range_html(
+                    &shared.label,
+                    &shared.default.start.to_string(),
+                    &shared.default.end.to_string(),
+                    "..",
+                    if bounded { Some((min, max)) } else { None },
+                    &options.step.to_string(),
+                    shared.disabled(),
+                    &submit_fn,
+                )
+            }
+
+            /// Parses `<start>..<end>`
+            fn parse(value: &str) -> Result<Self, Self::Err> {
+                let mut iter = value.splitn(2, "..");
+                match (iter.next().map(str::parse), iter.next().map(str::parse)) {
+                    (Some(Ok(start)), Some(Ok(end))) => Ok(start..end),
+                    _ => Err(format!("expected '<start>..<end>', got '{}'", value)),
+                }
+            }
+
+            fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+                self.update_with_options(value, &Self::DEFAULT_OPTIONS)
+            }
+
+            fn update_with_options(&mut self, value: &str, options: &Self::Options) -> Result<(), Self::Err> {
+                let (start, end) = update_range((self.start, self.end), value, options)?;
+                *self = start..end;
+                Ok(())
+            }
+        }
+
+        impl AsHtml for RangeInclusive<$ty> {
+            type Err = String;
+            type Options = NumberAttributes<$ty>;
+            const DEFAULT_OPTIONS: Self::Options = <$ty as AsHtml>::DEFAULT_OPTIONS;
+
+            fn register_header_footer(
+                types: &mut std::collections::HashSet<std::any::TypeId>,
+                header: &mut String,
+                footer: &mut String,
+            ) {
+                Range::<$ty>::register_header_footer(types, header, footer);
+            }
+
+            fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+                let (min, max) = (options.min as f64, options.max as f64);
+                let bounded = is_bounded!($ty, options);
+
+                range_html(
+                    &shared.label,
+                    &shared.default.start().to_string(),
+                    &shared.default.end().to_string(),
+                    "..=",
+                    if bounded { Some((min, max)) } else { None },
+                    &options.step.to_string(),
+                    shared.disabled(),
+                    &submit_fn,
+                )
+            }
+
+            /// Parses `<start>..=<end>`
+            fn parse(value: &str) -> Result<Self, Self::Err> {
+                let mut iter = value.splitn(2, "..=");
+                match (iter.next().map(str::parse), iter.next().map(str::parse)) {
+                    (Some(Ok(start)), Some(Ok(end))) => Ok(start..=end),
+                    _ => Err(format!("expected '<start>..=<end>', got '{}'", value)),
+                }
+            }
+
+            fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+                self.update_with_options(value, &Self::DEFAULT_OPTIONS)
+            }
+
+            fn update_with_options(&mut self, value: &str, options: &Self::Options) -> Result<(), Self::Err> {
+                let (start, end) = update_range((*self.start(), *self.end()), value, options)?;
+                *self = start..=end;
+                Ok(())
+            }
+        }
+    )+};
+}
+
+impl_ashtml_for_range!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_are_parsed() {
+        assert_eq!(Range::<i32>::parse("-2..5"), Ok(-2..5));
+        assert_eq!(RangeInclusive::<f32>::parse("0.5..=1"), Ok(0.5..=1.0));
+        assert!(Range::<u8>::parse("1..").is_err());
+        assert!(Range::<u8>::parse("1..=2").is_err());
+        assert!(RangeInclusive::<u8>::parse("1..2").is_err());
+    }
+
+    #[test]
+    fn range_ends_are_updated() {
+        let mut range = 0..10;
+        range.update("start:3").unwrap();
+        range.update("end:4").unwrap();
+        assert_eq!(range, 3..4);
+
+        let mut range = 0.0..=1.0;
+        range.update("end:2.5").unwrap();
+        assert_eq!(range, 0.0..=2.5);
+
+        assert!(range.update("middle:1").is_err());
+        assert!(range.update("start").is_err());
+        assert!(range.update("start:x").is_err());
+        assert_eq!(range, 0.0..=2.5);
+    }
+
+    #[test]
+    fn inverted_ranges_are_rejected() {
+        let mut range = 2..5;
+        assert!(range.update("start:6").is_err());
+        assert!(range.update("end:1").is_err());
+        assert_eq!(range, 2..5);
+
+        range.update("start:5").unwrap();
+        assert_eq!(range, 5..5);
+
+        let mut range = 0.0..=1.0;
+        assert!(range.update("start:NaN").is_err());
+        assert_eq!(range, 0.0..=1.0);
+    }
+
+    #[test]
+    fn range_ends_are_clamped_to_the_bounds() {
+        let options = NumberAttributes {
+            min: -10,
+            max: 10,
+            ..i32::DEFAULT_OPTIONS
+        };
+        let mut range = 0..5;
+        range.update_with_options("start:-20", &options).unwrap();
+        range.update_with_options("end:20", &options).unwrap();
+        assert_eq!(range, -10..10);
+
+        let options = NumberAttributes {
+            min: 0.0,
+            max: 1.0,
+            ..f64::DEFAULT_OPTIONS
+        };
+        let mut range = 0.5..=0.5;
+        range.update_with_options("end:1.5", &options).unwrap();
+        assert_eq!(range, 0.5..=1.0);
+        range.update_with_options("start:2", &options).unwrap();
+        assert_eq!(range, 1.0..=1.0);
+    }
+}
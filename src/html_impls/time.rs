@@ -0,0 +1,323 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, utils::Instant};
+
+use crate::{as_html::AsHtml, as_html::SharedOptions};
+
+/// The attributes of `Duration` and `Timer`
+#[derive(Clone)]
+pub struct DurationAttributes {
+    /// The unit the duration is displayed in: `"ms"`, `"s"` or `"min"`.
+    /// It can be changed in the inspector.
+    pub unit: &'static str,
+}
+
+const UNITS: &[(&str, f64)] = &[("ms", 0.001), ("s", 1.0), ("min", 60.0)];
+
+/// Parses `<number><unit>`, where the unit is `ms`, `s` or `min` and defaults to seconds
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let (number, scale) = UNITS
+        .iter()
+        .find(|(name, _)| value.ends_with(name))
+        .map_or((value, 1.0), |(name, seconds)| {
+            (&value[..value.len() - name.len()], *seconds)
+        });
+
+    let seconds = number
+        .trim()
+        .parse::<f64>()
+        .map(|number| number * scale)
+        .map_err(|_| format!("invalid duration '{}'", value))?;
+
+    if !seconds.is_finite() || seconds < 0.0 {
+        return Err(format!("invalid duration '{}'", value));
+    }
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+/// A number input and a unit selection, see `durationValue` in `static/time_ashtml.js`
+fn duration_input(seconds: f64, unit: &str, disabled: &str, submit_fn: &str) -> String {
+    let (unit, scale) = UNITS
+        .iter()
+        .copied()
+        .find(|(name, _)| *name == unit)
+        .unwrap_or(("s", 1.0));
+    let value = seconds / scale;
+
+    let units: String = UNITS
+        .iter()
+        .map(|(name, _)| {
+            let selected = if *name == unit { "selected" } else { "" };
+            format!(r#"<option value="{0}" {1}>{0}</option>"#, name, selected)
+        })
+        .collect();
+
+    format!(
+        r#"<span class="duration" data-unit="{unit}"><input type="number" min="0" step="any" value="{value}" {disabled} oninput="{submit_fn}(durationValue(this))"><select {disabled} onchange="changeDurationUnit(this)">{units}</select></span>"#,
+        unit = unit,
+        value = (value * 10_000.0).round() / 10_000.0,
+        disabled = disabled,
+        submit_fn = submit_fn,
+        units = units,
+    )
+}
+
+impl AsHtml for Duration {
+    type Err = String;
+    type Options = DurationAttributes;
+    const DEFAULT_OPTIONS: Self::Options = DurationAttributes { unit: "s" };
+
+    fn header() -> &'static str {
+        concat!(
+            "<script>",
+            include_str!("../../static/time_ashtml.js"),
+            "</script>"
+        )
+    }
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        format!(
+            r#"
+            <div class="row">
+                <label class="cell text-right">{label}:</label>
+                <div class="cell">{input}</div>
+            </div>
+            "#,
+            label = shared.label,
+            input = duration_input(
+                shared.default.as_secs_f64(),
+                options.unit,
+                shared.disabled(),
+                &submit_fn
+            ),
+        )
+    }
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        parse_duration(value)
+    }
+}
+
+/// A duration in seconds, formatted in `unit` like the duration inputs
+fn format_duration(seconds: f64, unit: &str) -> String {
+    let (unit, scale) = UNITS
+        .iter()
+        .copied()
+        .find(|(name, _)| *name == unit)
+        .unwrap_or(("s", 1.0));
+    format!("{:.2}{}", seconds / scale, unit)
+}
+
+/// The progress and the label of the pause button of `timer`, which are refreshed with the timer in the game
+fn timer_live_parts(timer: &Timer, unit: &str) -> (String, String) {
+    let progress = format!(
+        r#"<span data-live><progress max="1" value="{percent}"></progress> {elapsed} elapsed, {remaining} remaining</span>"#,
+        percent = timer.percent(),
+        elapsed = format_duration(timer.elapsed() as f64, unit),
+        remaining = format_duration((timer.duration() - timer.elapsed()) as f64, unit),
+    );
+    let pause_text = if timer.is_paused() { "Resume" } else { "Pause" };
+    (progress, format!("<span data-live>{}</span>", pause_text))
+}
+
+/// The progress, elapsed and remaining time and whether the timer is paused are rendered with the values from the game
+/// and refreshed every `watch_interval` milliseconds, like readonly fields.
+impl AsHtml for Timer {
+    type Err = String;
+    type Options = DurationAttributes;
+    const DEFAULT_OPTIONS: Self::Options = Duration::DEFAULT_OPTIONS;
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        let timer = &shared.default;
+        let disabled = shared.disabled();
+        let duration_submit_fn = format!("((value) => {}('duration:'+value))", submit_fn);
+        let (progress, pause_text) = timer_live_parts(timer, options.unit);
+
+        format!(
+            r#"
+            <div class="row">
+                <label class="cell text-right">{label}:</label>
+                <div class="cell timer">
+                    {duration_input}
+                    <label><input type="checkbox" {repeating_checked} {disabled} oninput="{submit_fn}('repeating:'+this.checked)"> repeating</label>
+                    <br />
+                    {progress}
+                    <button {disabled} onclick="{submit_fn}(toggleTimer(this))">{pause_text}</button>
+                    <button {disabled} onclick="{submit_fn}('reset')">Reset</button>
+                </div>
+            </div>
+            "#,
+            label = shared.label,
+            duration_input = duration_input(
+                timer.duration() as f64,
+                options.unit,
+                disabled,
+                &duration_submit_fn
+            ),
+            repeating_checked = if timer.repeating() { "checked" } else { "" },
+            progress = progress,
+            pause_text = pause_text,
+            disabled = disabled,
+            submit_fn = submit_fn,
+        )
+    }
+
+    fn live_html(&self, options: &Self::Options) -> String {
+        let (progress, pause_text) = timer_live_parts(self, options.unit);
+        progress + &pause_text
+    }
+
+    fn register_header_footer(
+        types: &mut std::collections::HashSet<std::any::TypeId>,
+        header: &mut String,
+        footer: &mut String,
+    ) {
+        Duration::register_header_footer(types, header, footer);
+        types.insert(std::any::TypeId::of::<super::LiveParts>());
+    }
+
+    /// Parses the duration of a non-repeating timer
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        parse_duration(value).map(|duration| Timer::new(duration, false))
+    }
+
+    fn update(&mut self, value: &str) -> Result<(), Self::Err> {
+        let mut iter = value.splitn(2, ':');
+        match (iter.next(), iter.next()) {
+            (Some("duration"), Some(duration)) => {
+                self.set_duration(parse_duration(duration)?.as_secs_f32())
+            }
+            (Some("repeating"), Some(repeating)) => self.set_repeating(
+                repeating
+                    .parse()
+                    .map_err(|_| format!("expected 'true' or 'false', got '{}'", repeating))?,
+            ),
+            (Some("pause"), None) => self.pause(),
+            (Some("unpause"), None) => self.unpause(),
+            (Some("reset"), None) => self.reset(),
+            _ => return Err(format!("unexpected timer update '{}'", value)),
+        }
+        Ok(())
+    }
+}
+
+/// Parses `now` or an offset from now, like `-2s` for two seconds ago
+fn parse_instant(value: &str) -> Result<Instant, String> {
+    let value = value.trim();
+    let now = Instant::now();
+    if value == "now" {
+        return Ok(now);
+    }
+
+    let instant = match value.strip_prefix('-') {
+        Some(offset) => now.checked_sub(parse_duration(offset)?),
+        None => now.checked_add(parse_duration(value.trim_start_matches('+'))?),
+    };
+    instant.ok_or_else(|| format!("invalid instant '{}'", value))
+}
+
+/// Shown relative to the current time, e.g. `1.50s ago`, and refreshed like the elapsed time of a `Timer`.
+/// It can be set to the current time.
+impl AsHtml for Instant {
+    type Err = String;
+    type Options = DurationAttributes;
+    const DEFAULT_OPTIONS: Self::Options = Duration::DEFAULT_OPTIONS;
+
+    fn as_html(shared: SharedOptions<Self>, options: Self::Options, submit_fn: String) -> String {
+        format!(
+            r#"
+            <div class="row">
+                <label class="cell text-right">{label}:</label>
+                <div class="cell">
+                    {relative}
+                    <button {disabled} onclick="{submit_fn}('now')">Now</button>
+                </div>
+            </div>
+            "#,
+            label = shared.label,
+            relative = shared.default.live_html(&options),
+            disabled = shared.disabled(),
+            submit_fn = submit_fn,
+        )
+    }
+
+    fn live_html(&self, options: &Self::Options) -> String {
+        let now = Instant::now();
+        let relative = if *self <= now {
+            let ago = now.duration_since(*self).as_secs_f64();
+            format!("{} ago", format_duration(ago, options.unit))
+        } else {
+            let until = self.duration_since(now).as_secs_f64();
+            format!("in {}", format_duration(until, options.unit))
+        };
+        format!("<span data-live>{}</span>", relative)
+    }
+
+    fn register_header_footer(
+        types: &mut std::collections::HashSet<std::any::TypeId>,
+        _header: &mut String,
+        _footer: &mut String,
+    ) {
+        types.insert(std::any::TypeId::of::<super::LiveParts>());
+    }
+
+    /// Parses `now` or an offset from now like `-2s`, with the units of durations
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        parse_instant(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_are_parsed_with_their_unit() {
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration(" 2 s "), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("0.5min"), Ok(Duration::from_secs(30)));
+
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("1h").is_err());
+        assert!(parse_duration("infs").is_err());
+        assert!(parse_duration("").is_err());
+    }
+
+    #[test]
+    fn instants_are_parsed_relative_to_now() {
+        let before = Instant::now();
+        let instant = parse_instant("-2s").unwrap();
+        assert!(instant <= before - Duration::from_secs(2) + Duration::from_millis(100));
+        assert!(parse_instant("+1min").unwrap() > Instant::now());
+        assert!(parse_instant("now").unwrap() >= before);
+        assert!(parse_instant("soon").is_err());
+    }
+
+    #[test]
+    fn timers_are_updated_by_property() {
+        let mut timer = Timer::from_seconds(2.0, false);
+        timer.update("duration:500ms").unwrap();
+        assert_eq!(timer.duration(), 0.5);
+        timer.update("repeating:true").unwrap();
+        assert!(timer.repeating());
+        timer.update("pause").unwrap();
+        assert!(timer.is_paused());
+        timer.update("unpause").unwrap();
+        assert!(!timer.is_paused());
+
+        assert!(timer.update("repeating:yes").is_err());
+        assert!(timer.update("stop").is_err());
+    }
+
+    #[test]
+    fn live_parts_show_the_elapsed_time() {
+        let mut timer = Timer::from_seconds(2.0, false);
+        timer.pause();
+        let html = timer.live_html(&DurationAttributes { unit: "ms" });
+        assert_eq!(html.matches("data-live").count(), 2);
+        assert!(html.contains("0.00ms elapsed, 2000.00ms remaining"));
+        assert!(html.contains("Resume"));
+    }
+}
@@ -1,10 +1,15 @@
 use flume::{unbounded as channel, Receiver, Sender};
 use tiny_http::{Method, Request, Response, Server, StatusCode};
 
-type Event = (String, String);
+/// A `PUT /` of `field:value`, with the sender for the result of applying it in the app
+type Event = (String, String, Sender<Result<(), String>>);
+
+/// How long a `PUT /` waits for the app to apply the update
+const UPDATE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
 
 pub struct InspectorServer {
     pub rx: Receiver<Event>,
+    pub requests: Receiver<AppRequest>,
     pub handle: std::thread::JoinHandle<()>,
 }
 
@@ -21,21 +26,81 @@ impl ServerConfig {
     }
 }
 
+/// A request which can only be answered from inside the app, e.g. `GET /entities`.
+pub struct AppRequest {
+    request: Request,
+    pub body: String,
+}
+
+impl AppRequest {
+    pub fn method(&self) -> &Method {
+        self.request.method()
+    }
+
+    /// The requested url without its query string
+    pub fn path(&self) -> &str {
+        let url = self.request.url();
+        url.splitn(2, '?').next().unwrap_or(url)
+    }
+
+    /// The query string of the requested url without the `?`, or an empty string
+    pub fn query(&self) -> &str {
+        self.request.url().splitn(2, '?').nth(1).unwrap_or("")
+    }
+
+    pub fn respond_html(self, html: String) {
+        let content_type =
+            tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"text/html"[..]).unwrap();
+        self.respond(Response::from_string(html).with_header(content_type));
+    }
+
+    pub fn respond_status(self, status: u16) {
+        self.respond(Response::new_empty(StatusCode(status)));
+    }
+
+    /// Responds with `400 Bad Request` and the error as text
+    pub fn respond_error(self, error: String) {
+        self.respond(Response::from_string(error).with_status_code(StatusCode(400)));
+    }
+
+    fn respond<R: std::io::Read>(self, response: Response<R>) {
+        let url = self.request.url().to_string();
+        if let Err(e) = self.request.respond(response) {
+            eprintln!("failed to respond to '{}': {}", url, e);
+        }
+    }
+}
+
 fn handle_request(
     config: &ServerConfig,
     mut req: Request,
     tx: &Sender<Event>,
+    app_tx: &Sender<AppRequest>,
 ) -> Result<(), std::io::Error> {
-    match req.method() {
+    if req.url() != "/" {
+        let body = read_body(&mut req)?;
+        app_tx.send(AppRequest { request: req, body }).unwrap();
+        return Ok(());
+    }
+
+    let (status, error) = match req.method() {
         Method::Get => return handle_get(config, req),
-        Method::Put => {
-            if let Some(event) = parse_body(&mut req)? {
-                tx.send(event).unwrap();
+        Method::Put => match parse_body(&mut req)? {
+            Some((field, data)) => {
+                // responds once the update is applied, so that the page knows whether it was valid
+                let (result_tx, result_rx) = channel();
+                tx.send((field, data, result_tx)).unwrap();
+                match result_rx.recv_timeout(UPDATE_TIMEOUT) {
+                    Ok(Ok(())) => (200, String::new()),
+                    Ok(Err(e)) => (400, e),
+                    Err(_) => (503, "the update wasn't applied by the app".to_string()),
+                }
             }
-        }
-        _ => {}
-    }
-    req.respond(Response::new_empty(StatusCode(200)))?;
+            None => (400, "expected '<field>:<value>'".to_string()),
+        },
+        _ => (405, String::new()),
+    };
+    req.respond(Response::from_string(error).with_status_code(StatusCode(status)))?;
     Ok(())
 }
 
@@ -47,14 +112,17 @@ fn handle_get(config: &ServerConfig, req: Request) -> Result<(), std::io::Error>
     response.add_header(content_type);
     req.respond(response)
 }
-fn parse_body(req: &mut Request) -> Result<Option<(String, String)>, std::io::Error> {
+fn read_body(req: &mut Request) -> Result<String, std::io::Error> {
     let mut buf = Vec::with_capacity(req.body_length().unwrap_or_default());
     let reader = req.as_reader();
     reader.read_to_end(&mut buf)?;
 
     let invalid_data = |e| std::io::Error::new(std::io::ErrorKind::InvalidData, e);
 
-    let event = String::from_utf8(buf).map_err(invalid_data)?;
+    String::from_utf8(buf).map_err(invalid_data)
+}
+fn parse_body(req: &mut Request) -> Result<Option<(String, String)>, std::io::Error> {
+    let event = read_body(req)?;
 
     let mut iter = event.splitn(2, ':');
     match (iter.next(), iter.next()) {
@@ -66,17 +134,22 @@ fn parse_body(req: &mut Request) -> Result<Option<(String, String)>, std::io::Er
 impl InspectorServer {
     pub fn start_in_background(addr: &str, config: ServerConfig) -> Result<Self, Error> {
         let (tx, rx) = channel();
+        let (app_tx, requests) = channel();
 
         let listener = Server::http(addr)?;
 
         let handle = std::thread::spawn(move || {
             for req in listener.incoming_requests() {
-                if let Err(e) = handle_request(&config, req, &tx) {
+                if let Err(e) = handle_request(&config, req, &tx, &app_tx) {
                     dbg!(e);
                 }
             }
         });
 
-        Ok(InspectorServer { rx, handle })
+        Ok(InspectorServer {
+            rx,
+            requests,
+            handle,
+        })
     }
 }
@@ -58,16 +58,140 @@
 //! ```
 //! The attribute on the struct will accept fields of the type [`InspectableOptions`],
 //! while the attributes on the fields accept those of their [`<Type as AsHtml>::Options`](as_html::AsHtml).
+//!
+//! ## Readonly fields
+//! Fields marked with `#[inspectable(readonly)]` can't be edited in the inspector,
+//! instead they display the current value of the field, which is useful for values computed by your game.
+//! Their type has to implement [`Clone`].
+//! ```rust
+//! # use bevy_contrib_inspector::Inspectable;
+//! #[derive(Inspectable, Default)]
+//! struct Data {
+//!     #[inspectable(min = 0.0, max = 10.0)]
+//!     acceleration: f32,
+//!     #[inspectable(readonly)]
+//!     current_speed: f32,
+//! }
+//! ```
+//!
+//! ## Entities
+//! The inspector also has an entity view at `/entities`, which lists the entities of your app.
+//! Components registered using [`RegisterInspectable::register_inspectable_component`] can be edited there,
+//! removed from an entity or inserted with their [`Default`] value.
+//! `Transform` and `GlobalTransform` are registered by default.
+//! ```rust,no_run
+//! use bevy_contrib_inspector::{AsHtml, InspectorPlugin, RegisterInspectable};
+//! # use bevy::prelude::*;
+//!
+//! # #[derive(bevy_contrib_inspector::Inspectable, Default)] struct Data {}
+//! #[derive(AsHtml, Default, Clone)]
+//! struct Velocity {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! fn main() {
+//!     App::build()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugin(InspectorPlugin::<Data>::new())
+//!         .register_inspectable_component::<Velocity>()
+//!         .run();
+//! }
+//! ```
+//!
+//! ## Assets
+//! Similarly, assets registered using [`RegisterInspectable::register_inspectable_asset`]
+//! can be edited in the asset view at `/assets`. `ColorMaterial`s are registered by default.
+//!
+//! ## Diagnostics
+//! If your app has diagnostics, e.g. from the `FrameTimeDiagnosticsPlugin`,
+//! the inspector page displays their current and average values and a graph of their recent history.
+//!
+//! ## Time control
+//! The [`TimeControlPlugin`] adds a panel to the inspector page which can pause the systems in the [`TIME_CONTROLLED_STAGE`],
+//! advance them by a single frame and scale their delta time.
+//! The time scale is opt-in: the `Time` resource is left untouched, so systems have to use `TimeControl::delta_seconds` for it to apply.
+//! ```rust,no_run
+//! use bevy_contrib_inspector::{InspectorPlugin, TimeControl, TimeControlPlugin, TIME_CONTROLLED_STAGE};
+//! # use bevy::prelude::*;
+//!
+//! # #[derive(bevy_contrib_inspector::Inspectable, Default)] struct Data {}
+//! fn main() {
+//!     App::build()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugin(InspectorPlugin::<Data>::new())
+//!         .add_plugin(TimeControlPlugin)
+//!         .add_system_to_stage(TIME_CONTROLLED_STAGE, movement.system())
+//!         .run();
+//! }
+//!
+//! fn movement(time: Res<Time>, time_control: Res<TimeControl>, mut query: Query<&mut Transform>) {
+//!     for mut transform in query.iter_mut() {
+//!         transform.translation.x += 10.0 * time_control.delta_seconds(&time);
+//!     }
+//! }
+//! ```
+//!
+//! ## Asset paths
+//! `PathBuf` and [`AssetFile`] fields are edited using a picker which suggests the files in the asset folder,
+//! optionally filtered by their extension using `#[inspectable(extensions = "png,jpg")]`.
+//!
+//! `Handle<T>` fields show the path of the asset they point to, if it was loaded by the `AssetServer`,
+//! and can be switched to any other loaded asset of the same type.
+//! The asset type needs to be registered using [`RegisterInspectable::register_inspectable_handle`]
+//! or [`RegisterInspectable::register_inspectable_asset`], textures, meshes and color materials are registered by the plugin.
+//!
+//! ## Gradients and curves
+//! [`Gradient`] and [`Curve`] fields are edited by dragging their color stops and keyframes on a canvas.
+//! Click on an empty spot to add one, the keyframes of a curve can be removed by double-clicking them.
+//! Both can be sampled from your systems:
+//! ```rust
+//! use bevy_contrib_inspector::{Curve, Gradient, Inspectable};
+//!
+//! #[derive(Inspectable, Default)]
+//! struct Data {
+//!     sky: Gradient,
+//!     #[inspectable(max_time = 60.0, max = 10.0)]
+//!     spawn_rate: Curve,
+//! }
+//!
+//! # let data = Data::default();
+//! let color = data.sky.sample(0.25);
+//! let spawn_rate = data.spawn_rate.sample(12.0);
+//! ```
+//!
+//! ## Flags
+//! A `HashSet` or `BTreeSet` of a derived enum without fields is edited as a group of checkboxes, see [`UnitEnum`](as_html::UnitEnum).
+//! Types generated by `bitflags!` can be edited the same way using [`impl_as_html_for_bitflags!`].
+mod assets;
+mod curve;
+mod diagnostics;
+mod entities;
+mod files;
+mod gradient;
+mod handles;
 mod html_impls;
 mod inspector_server;
+mod page;
+mod panels;
 mod plugin;
+mod registry;
+mod router;
+mod time_control;
 
 /// derives [AsHtml](trait.AsHtml.html)
 pub use bevy_contrib_inspector_derive::AsHtml;
 /// derives [Inspectable](trait.Inspectable.html)
 pub use bevy_contrib_inspector_derive::Inspectable;
 
+pub use curve::{Curve, CurveAttributes, Interpolation, Keyframe};
+pub use gradient::{ColorStop, Gradient};
+pub use html_impls::AssetFile;
+#[doc(hidden)]
+pub use page::html_page;
 pub use plugin::InspectorPlugin;
+pub use registry::RegisterInspectable;
+pub use time_control::{TimeControl, TimeControlPlugin, TIME_CONTROLLED_STAGE};
 
 /// This trait describes how a struct should be rendered in HTML.
 /// It is meant to be derived, see the [crate-level docs](index.html) for that.
@@ -76,11 +200,20 @@ pub trait Inspectable: Send + Sync + 'static {
     fn html() -> String;
     /// When recieving a PUT request, its body will be parsed as `$field:$value`.
     /// The update function is supposed to parse the value into its correct type and set it on `self`.
-    fn update(&mut self, field: &str, value: &str);
+    /// The request fails if this returns an error.
+    fn update(&mut self, field: &str, value: &str) -> Result<(), String>;
     /// Describes things like the webserver's port. Can be set with a `#[inspector(option = value)]` on the struct.
     fn options() -> InspectableOptions {
         InspectableOptions::default()
     }
+    /// The HTML of the fields marked with `#[inspectable(readonly)]`, rendered with their current values.
+    /// Of the other fields, only the parts showing live values are included, like the elapsed time of a `Timer`.
+    ///
+    /// The inspector page polls these every [`InspectableOptions::watch_interval`] milliseconds and replaces the fields
+    /// (or their live parts) with them.
+    fn watched(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
 }
 
 /// The `InspectableOptions` control parameters like the webserver's port.
@@ -88,10 +221,15 @@ pub trait Inspectable: Send + Sync + 'static {
 /// They can be set when deriving the trait using `#[inspector(option = value)], as described in the [Attributes](index.html#attributes) section.
 pub struct InspectableOptions {
     pub port: u16,
+    /// How often the `#[inspectable(readonly)]` fields and live values like the elapsed time of timers are updated, in milliseconds
+    pub watch_interval: u32,
 }
 impl Default for InspectableOptions {
     fn default() -> Self {
-        InspectableOptions { port: 5676 }
+        InspectableOptions {
+            port: 5676,
+            watch_interval: 200,
+        }
     }
 }
 
@@ -102,6 +240,19 @@ pub mod as_html {
     pub struct SharedOptions<T> {
         pub label: std::borrow::Cow<'static, str>,
         pub default: T,
+        /// Set by `#[inspectable(readonly)]`. The value should be displayed, but not be editable.
+        pub readonly: bool,
+    }
+
+    impl<T> SharedOptions<T> {
+        /// `"disabled"` if the field is readonly, to be used as an attribute of an `<input>`
+        pub fn disabled(&self) -> &'static str {
+            if self.readonly {
+                "disabled"
+            } else {
+                ""
+            }
+        }
     }
 
     pub use crate::AsHtml;
@@ -162,4 +313,23 @@ pub trait AsHtml: Sized + 'static {
         *self = value;
         Ok(())
     }
+
+    /// Like [`update`](AsHtml::update), but with the attributes of the field.
+    /// This is called for the fields of an [`Inspectable`] struct, and for the elements of containers
+    /// and the fields of derived types with the options they are rendered with.
+    fn update_with_options(
+        &mut self,
+        value: &str,
+        _options: &Self::Options,
+    ) -> Result<(), Self::Err> {
+        self.update(value)
+    }
+
+    #[doc(hidden)]
+    /// The parts of the HTML of `self` marked with `data-live`, in the order [`as_html`](AsHtml::as_html) renders them.
+    /// They are refreshed every [`InspectableOptions::watch_interval`] milliseconds
+    /// for types registering [`LiveParts`](as_html::LiveParts) in [`register_header_footer`](AsHtml::register_header_footer).
+    fn live_html(&self, _options: &Self::Options) -> String {
+        String::new()
+    }
 }
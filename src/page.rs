@@ -0,0 +1,45 @@
+/// How often changes are sent to the server at most, in milliseconds.
+const HANDLE_CHANGE_THROTTLE: u32 = 10; // used in ../static/script.js
+
+/// Wraps the rendered inputs into the html document served by the inspector.
+///
+/// This is used by the code generated from `#[derive(Inspectable)]` and by the entity and asset views.
+pub fn html_page(header: &str, inputs: &str, footer: &str) -> String {
+    format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8" />
+{header}
+<style>{css}</style>
+</head>
+<body>
+    <script>
+    const handleChangeThrottle = {inspectable_throttle};
+    {js}
+    </script>
+
+    <nav>
+        <a href="/">Resource</a>
+        <a href="/entities">Entities</a>
+        <a href="/assets">Assets</a>
+    </nav>
+
+    <div id="panels"></div>
+
+    <div id="inputs">
+    {inputs}
+    </div>
+
+    {footer}
+</body>
+</html>"#,
+        header = header,
+        footer = footer,
+        css = include_str!("../static/style.css"),
+        js = include_str!("../static/script.js"),
+        inputs = inputs,
+        inspectable_throttle = HANDLE_CHANGE_THROTTLE,
+    )
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use tiny_http::Method;
+
+use crate::inspector_server::AppRequest;
+use crate::router::Router;
+
+/// A box shown above the inputs of every inspector page, like the time controls.
+pub(crate) struct Panel {
+    pub name: &'static str,
+    /// If set, the panel is reloaded every `refresh_interval` milliseconds.
+    pub refresh_interval: Option<u32>,
+    pub html: fn(&Resources) -> String,
+    /// Called with `action` when `panelAction(name, action)` is called in the browser.
+    pub update: fn(&Resources, &str),
+}
+
+#[derive(Default)]
+pub(crate) struct Panels(Vec<Panel>);
+
+impl Panels {
+    /// Adds the panel to every inspector page, unless there already is one with the same name.
+    pub fn add_panel(app: &mut AppBuilder, panel: Panel) {
+        Router::add_route(app, "/panels", handle_request);
+
+        let resources = app.resources_mut();
+        if !resources.contains::<Panels>() {
+            resources.insert(Panels::default());
+        }
+
+        let mut panels = resources.get_mut::<Panels>().unwrap();
+        let exists = panels.0.iter().any(|existing| existing.name == panel.name);
+        if !exists {
+            panels.0.push(panel);
+        }
+    }
+}
+
+/// Handles
+/// - `GET /panels`: all panels
+/// - `GET /panels/<name>`: the content of one panel
+/// - `PUT /panels/<name>` with the action, e.g. `pause`
+fn handle_request(_: &mut World, resources: &mut Resources, request: AppRequest) {
+    let panels = match resources.get::<Panels>() {
+        Some(panels) => panels,
+        None => return request.respond_status(404),
+    };
+
+    let name = request.path()["/panels".len()..].trim_matches('/');
+    let panel = panels.0.iter().find(|panel| panel.name == name);
+
+    let method = request.method().clone();
+    match (method, panel) {
+        (Method::Get, None) if name.is_empty() => {
+            let html: String = panels
+                .0
+                .iter()
+                .map(|panel| {
+                    format!(
+                        r#"<div class="panel" id="panel-{name}" data-name="{name}" data-refresh="{refresh}">{html}</div>"#,
+                        name = panel.name,
+                        refresh = panel.refresh_interval.unwrap_or(0),
+                        html = (panel.html)(resources),
+                    )
+                })
+                .collect();
+            request.respond_html(html);
+        }
+        (Method::Get, Some(panel)) => request.respond_html((panel.html)(resources)),
+        (Method::Put, Some(panel)) => {
+            (panel.update)(resources, &request.body);
+            request.respond_status(200);
+        }
+        _ => request.respond_status(404),
+    }
+}
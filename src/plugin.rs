@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 
-use crate::inspector_server::{InspectorServer, ServerConfig};
-use crate::Inspectable;
+use crate::inspector_server::{AppRequest, InspectorServer, ServerConfig};
+use crate::registry::registry;
+use crate::router::Router;
+use crate::{assets, diagnostics, entities, files, handles, Inspectable};
 
 #[derive(Default, Clone)]
 pub struct InspectorPlugin<T> {
@@ -16,9 +18,24 @@ impl<T> InspectorPlugin<T> {
 }
 
 impl<T: Inspectable> InspectorPlugin<T> {
-    fn check(server: Res<InspectorServer>, mut inspectable_data: ResMut<T>) {
-        if let Ok((field, data)) = server.rx.try_recv() {
-            inspectable_data.update(&field, &data);
+    /// Applies an update submitted by the inspector and sends back whether it succeeded.
+    ///
+    /// This needs all `Resources` to resolve the `Handle<T>` fields, see [`handles::with_resources`].
+    fn check(_: &mut World, resources: &mut Resources) {
+        let event = match resources.get::<InspectorServer>() {
+            Some(server) => server.rx.try_recv(),
+            None => return,
+        };
+        if let Ok((field, data, result)) = event {
+            if let Some(mut inspectable_data) = resources.get_mut::<T>() {
+                let update =
+                    handles::with_resources(resources, || inspectable_data.update(&field, &data));
+                if let Err(e) = &update {
+                    eprintln!("{}", e);
+                }
+                // the request may have timed out already
+                let _ = result.send(update);
+            }
         }
     }
 
@@ -38,6 +55,21 @@ impl<T: Inspectable> InspectorPlugin<T> {
 
         commands.insert_resource(server);
     }
+
+    /// Responds to `GET /watch` with the current values of the readonly fields, see [`Inspectable::watched`].
+    fn watch(_: &mut World, resources: &mut Resources, request: AppRequest) {
+        let inspectable_data = match resources.get::<T>() {
+            Some(inspectable_data) => inspectable_data,
+            None => return request.respond_status(404),
+        };
+
+        let html: String = inspectable_data
+            .watched()
+            .into_iter()
+            .map(|(field, html)| format!(r#"<template data-field="{}">{}</template>"#, field, html))
+            .collect();
+        request.respond_html(html);
+    }
 }
 
 #[cfg(not(feature = "native"))]
@@ -92,5 +124,22 @@ impl<T: Inspectable + Default> Plugin for InspectorPlugin<T> {
         app.add_resource(T::default())
             .add_startup_system(Self::start_server.system())
             .add_system(Self::check.system());
+
+        Router::add_route(app, "/watch", Self::watch);
+        Router::add_route(app, "/entities", entities::handle_request);
+        Router::add_route(app, "/assets", assets::handle_request);
+        Router::add_route(app, "/files", files::handle_request);
+        Router::add_route(app, "/handles", handles::handle_request);
+        diagnostics::add_diagnostics_panel(app);
+
+        let mut registry = registry(app.resources_mut());
+        registry.register_component::<Transform>();
+        registry.register_component::<GlobalTransform>();
+        registry.register_asset::<ColorMaterial>(|material| ColorMaterial {
+            color: material.color,
+            texture: material.texture.clone(),
+        });
+        registry.register_handle_type::<Texture>();
+        registry.register_handle_type::<Mesh>();
     }
 }
@@ -0,0 +1,208 @@
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use bevy::asset::{Asset, HandleId};
+use bevy::prelude::*;
+
+use crate::as_html::{AsHtml, SharedOptions};
+
+type RegisterHeaderFooter = fn(&mut HashSet<TypeId>, &mut String, &mut String);
+
+/// The functions needed to display, edit, add and remove a component in the entity view.
+pub(crate) struct ComponentRegistration {
+    pub type_id: TypeId,
+    pub name: &'static str,
+    pub has: fn(&World, Entity) -> bool,
+    pub html: fn(&World, Entity, String) -> Option<String>,
+    pub update: fn(&mut World, Entity, &str) -> Result<(), String>,
+    pub insert_default: fn(&mut World, Entity) -> bool,
+    pub remove: fn(&mut World, Entity) -> bool,
+    pub register_header_footer: RegisterHeaderFooter,
+}
+
+/// The functions needed to display and edit the assets of one type in the asset view.
+pub(crate) struct AssetRegistration {
+    pub type_id: TypeId,
+    pub name: &'static str,
+    /// `None` if the `Assets<T>` resource doesn't exist
+    pub handles: fn(&Resources) -> Option<Vec<HandleId>>,
+    pub html: Box<dyn Fn(&Resources, HandleId, String) -> Option<String> + Send + Sync>,
+    pub update: fn(&Resources, HandleId, &str) -> Result<(), String>,
+    pub register_header_footer: RegisterHeaderFooter,
+}
+
+/// An asset type whose loaded assets can be picked in `Handle<T>` fields.
+pub(crate) struct HandleTypeRegistration {
+    pub type_id: TypeId,
+    /// The full type name, which `Handle<T>` fields use to request the loaded assets at `/handles/<type>`
+    pub type_name: &'static str,
+    /// `None` if the `Assets<T>` resource doesn't exist
+    pub handles: fn(&Resources) -> Option<Vec<HandleId>>,
+}
+
+/// The components and assets which are shown in the entity and asset views.
+#[derive(Default)]
+pub(crate) struct InspectableRegistry {
+    pub components: Vec<ComponentRegistration>,
+    pub assets: Vec<AssetRegistration>,
+    pub handle_types: Vec<HandleTypeRegistration>,
+}
+
+impl InspectableRegistry {
+    pub fn register_component<C>(&mut self)
+    where
+        C: AsHtml + Default + Clone + Send + Sync,
+    {
+        let type_id = TypeId::of::<C>();
+        if self.components.iter().any(|c| c.type_id == type_id) {
+            return;
+        }
+
+        self.components.push(ComponentRegistration {
+            type_id,
+            name: short_type_name::<C>(),
+            has: |world, entity| world.get::<C>(entity).is_ok(),
+            html: |world, entity, submit_fn| {
+                let component = world.get::<C>(entity).ok()?;
+                let shared = SharedOptions {
+                    label: Cow::Borrowed(short_type_name::<C>()),
+                    default: C::clone(&component),
+                    readonly: false,
+                };
+                Some(C::as_html(shared, C::DEFAULT_OPTIONS, submit_fn))
+            },
+            update: |world, entity, value| {
+                let mut component = world
+                    .get_mut::<C>(entity)
+                    .map_err(|_| format!("the entity has no '{}'", short_type_name::<C>()))?;
+                component
+                    .update_with_options(value, &C::DEFAULT_OPTIONS)
+                    .map_err(|e| format!("failed to parse '{}': {:?}", short_type_name::<C>(), e))
+            },
+            insert_default: |world, entity| world.insert_one(entity, C::default()).is_ok(),
+            remove: |world, entity| world.remove_one::<C>(entity).is_ok(),
+            register_header_footer: C::register_header_footer,
+        });
+    }
+
+    /// `clone` is needed because `AsHtml::as_html` takes its value by ownership,
+    /// and some of bevy's assets like `ColorMaterial` don't implement `Clone`.
+    pub fn register_asset<A>(&mut self, clone: fn(&A) -> A)
+    where
+        A: Asset + AsHtml,
+    {
+        let type_id = TypeId::of::<A>();
+        if self.assets.iter().any(|a| a.type_id == type_id) {
+            return;
+        }
+        self.register_handle_type::<A>();
+
+        self.assets.push(AssetRegistration {
+            type_id,
+            name: short_type_name::<A>(),
+            handles: |resources| {
+                let assets = resources.get::<Assets<A>>()?;
+                Some(assets.iter().map(|(id, _)| id).collect())
+            },
+            html: Box::new(move |resources, id, submit_fn| {
+                let assets = resources.get::<Assets<A>>()?;
+                let shared = SharedOptions {
+                    label: Cow::Borrowed(short_type_name::<A>()),
+                    default: clone(assets.get(id)?),
+                    readonly: false,
+                };
+                Some(A::as_html(shared, A::DEFAULT_OPTIONS, submit_fn))
+            }),
+            update: |resources, id, value| {
+                let mut assets = resources
+                    .get_mut::<Assets<A>>()
+                    .ok_or_else(|| format!("there are no '{}' assets", short_type_name::<A>()))?;
+                let asset = assets.get_mut(id).ok_or_else(|| {
+                    format!("the '{}' asset doesn't exist", short_type_name::<A>())
+                })?;
+                asset
+                    .update_with_options(value, &A::DEFAULT_OPTIONS)
+                    .map_err(|e| format!("failed to parse '{}': {:?}", short_type_name::<A>(), e))
+            },
+            register_header_footer: A::register_header_footer,
+        });
+    }
+
+    pub fn register_handle_type<T: Asset>(&mut self) {
+        let type_id = TypeId::of::<T>();
+        if self.handle_types.iter().any(|h| h.type_id == type_id) {
+            return;
+        }
+
+        self.handle_types.push(HandleTypeRegistration {
+            type_id,
+            type_name: std::any::type_name::<T>(),
+            handles: |resources| {
+                let assets = resources.get::<Assets<T>>()?;
+                Some(assets.iter().map(|(id, _)| id).collect())
+            },
+        });
+    }
+}
+
+/// Registers types with the inspector.
+pub trait RegisterInspectable {
+    /// Shows the component `C` in the entity view at `/entities`,
+    /// where it can be edited, removed from an entity, or inserted with its [`Default`] value.
+    fn register_inspectable_component<C>(&mut self) -> &mut Self
+    where
+        C: AsHtml + Default + Clone + Send + Sync;
+
+    /// Shows the assets of type `A` in the asset view at `/assets`, where they can be edited.
+    ///
+    /// `ColorMaterial` is registered by the [`InspectorPlugin`](crate::InspectorPlugin).
+    fn register_inspectable_asset<A>(&mut self) -> &mut Self
+    where
+        A: Asset + AsHtml + Clone;
+
+    /// Lists the loaded assets of type `T` in the dropdown of `Handle<T>` fields.
+    ///
+    /// This is done for the assets registered with [`register_inspectable_asset`](RegisterInspectable::register_inspectable_asset),
+    /// `Texture` and `Mesh` are registered by the [`InspectorPlugin`](crate::InspectorPlugin).
+    fn register_inspectable_handle<T: Asset>(&mut self) -> &mut Self;
+}
+
+impl RegisterInspectable for AppBuilder {
+    fn register_inspectable_component<C>(&mut self) -> &mut Self
+    where
+        C: AsHtml + Default + Clone + Send + Sync,
+    {
+        registry(self.resources_mut()).register_component::<C>();
+        self
+    }
+
+    fn register_inspectable_asset<A>(&mut self) -> &mut Self
+    where
+        A: Asset + AsHtml + Clone,
+    {
+        registry(self.resources_mut()).register_asset::<A>(A::clone);
+        self
+    }
+
+    fn register_inspectable_handle<T: Asset>(&mut self) -> &mut Self {
+        registry(self.resources_mut()).register_handle_type::<T>();
+        self
+    }
+}
+
+/// Returns the registry, inserting it if it doesn't exist yet.
+pub(crate) fn registry(resources: &mut Resources) -> ResMut<'_, InspectableRegistry> {
+    if !resources.contains::<InspectableRegistry>() {
+        resources.insert(InspectableRegistry::default());
+    }
+    resources.get_mut::<InspectableRegistry>().unwrap()
+}
+
+/// `bevy::transform::components::Transform` -> `Transform`
+pub(crate) fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let path_end = name.find('<').unwrap_or_else(|| name.len());
+    let start = name[..path_end].rfind("::").map_or(0, |i| i + 2);
+    &name[start..]
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::inspector_server::{AppRequest, InspectorServer};
+
+type Handler = dyn Fn(&mut World, &mut Resources, AppRequest) + Send + Sync;
+
+/// Dispatches the requests the inspector server can't answer on its own
+/// (everything except the main page) to the handler registered for their path.
+#[derive(Default, Clone)]
+pub(crate) struct Router {
+    routes: Vec<(&'static str, Arc<Handler>)>,
+}
+
+impl Router {
+    /// Registers `handler` for every path starting with `prefix`, unless there already is a handler for it.
+    ///
+    /// The router and the system answering the requests are added to the app if they don't exist yet.
+    pub fn add_route<F>(app: &mut AppBuilder, prefix: &'static str, handler: F)
+    where
+        F: Fn(&mut World, &mut Resources, AppRequest) + Send + Sync + 'static,
+    {
+        if !app.resources().contains::<Router>() {
+            app.add_resource(Router::default())
+                .add_system(Router::handle_requests.system());
+        }
+
+        let mut router = app.resources_mut().get_mut::<Router>().unwrap();
+        let exists = router
+            .routes
+            .iter()
+            .any(|(existing, _)| *existing == prefix);
+        if !exists {
+            router.routes.push((prefix, Arc::new(handler)));
+        }
+    }
+
+    pub fn handle_requests(world: &mut World, resources: &mut Resources) {
+        let requests: Vec<AppRequest> = match resources.get::<InspectorServer>() {
+            Some(server) => server.requests.try_iter().collect(),
+            None => return,
+        };
+        if requests.is_empty() {
+            return;
+        }
+
+        // cloned so that the handlers can borrow the resources mutably
+        let router = match resources.get::<Router>() {
+            Some(router) => Router::clone(&router),
+            None => return,
+        };
+
+        for request in requests {
+            let handler = router
+                .routes
+                .iter()
+                .find(|(prefix, _)| request.path().starts_with(prefix));
+
+            match handler {
+                Some((_, handler)) => handler(world, resources, request),
+                None => request.respond_status(404),
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use bevy::app::stage;
+use bevy::prelude::*;
+
+use crate::panels::{Panel, Panels};
+
+/// The stage in which systems are paused, stepped and slowed down by the [`TimeControl`].
+///
+/// It runs right after `stage::UPDATE`. Only the systems added to this stage are paused.
+pub const TIME_CONTROLLED_STAGE: &str = "time_controlled";
+
+/// Pauses, resumes and single-steps the systems in the [`TIME_CONTROLLED_STAGE`] and scales time for them.
+///
+/// It only controls systems that opt in: systems in other stages keep running unless they use
+/// [`TimeControl::run_criteria`] or [`TimeControl::is_running`], and the `Time` resource isn't changed,
+/// so the `time_scale` only applies to systems using [`TimeControl::delta_seconds`].
+///
+/// It is controlled from the time panel on the inspector page, but can also be changed from your own systems.
+pub struct TimeControl {
+    pub paused: bool,
+    /// The factor by which [`TimeControl::delta_seconds`] differs from real time.
+    pub time_scale: f32,
+    steps: u32,
+    running: bool,
+}
+
+impl Default for TimeControl {
+    fn default() -> Self {
+        TimeControl {
+            paused: false,
+            time_scale: 1.0,
+            steps: 0,
+            running: true,
+        }
+    }
+}
+
+impl TimeControl {
+    /// Lets the time controlled systems run for one more frame while paused.
+    ///
+    /// Does nothing while the systems are running, so that steps don't pile up until the next pause.
+    pub fn step(&mut self) {
+        if self.paused {
+            self.steps += 1;
+        }
+    }
+
+    /// Whether the time controlled systems run in this frame.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// The scaled delta time of this frame, or zero if the time controlled systems don't run.
+    pub fn delta_seconds(&self, time: &Time) -> f32 {
+        if self.running {
+            time.delta_seconds() * self.time_scale
+        } else {
+            0.0
+        }
+    }
+
+    /// The run criteria used for the [`TIME_CONTROLLED_STAGE`].
+    pub fn run_criteria(time_control: Res<TimeControl>) -> ShouldRun {
+        if time_control.running {
+            ShouldRun::Yes
+        } else {
+            ShouldRun::No
+        }
+    }
+
+    /// Decides whether the time controlled systems run in this frame, consuming a step if necessary.
+    fn update(mut time_control: ResMut<TimeControl>) {
+        time_control.running = if !time_control.paused {
+            // steps left over from before resuming aren't taken after the next pause
+            time_control.steps = 0;
+            true
+        } else if time_control.steps > 0 {
+            time_control.steps -= 1;
+            true
+        } else {
+            false
+        };
+    }
+
+    fn panel_html(resources: &Resources) -> String {
+        let time_control = match resources.get::<TimeControl>() {
+            Some(time_control) => time_control,
+            None => return String::new(),
+        };
+
+        let (state, toggle_action, toggle_label) = if time_control.paused {
+            ("paused", "resume", "Resume")
+        } else {
+            ("running", "pause", "Pause")
+        };
+
+        format!(
+            r#"
+            <b>Time control</b>
+            <div class="row">
+                <span class="cell">Applies to the systems in the time controlled stage, the <code>Time</code> resource isn't scaled.</span>
+            </div>
+            <div class="row">
+                <span class="cell text-right">state:</span>
+                <div class="cell">
+                    {state}
+                    <button onclick="panelAction('time', '{toggle_action}')">{toggle_label}</button>
+                    <button onclick="panelAction('time', 'step')" {step_disabled}>Step</button>
+                </div>
+            </div>
+            <div class="row">
+                <label for="time-scale" class="cell text-right">time scale:</label>
+                <input class="cell" type="number" min="0" step="0.1" value="{time_scale}" onchange="panelAction('time', 'scale:' + this.value)" id="time-scale">
+            </div>
+            "#,
+            state = state,
+            toggle_action = toggle_action,
+            toggle_label = toggle_label,
+            step_disabled = if time_control.paused { "" } else { "disabled" },
+            time_scale = time_control.time_scale,
+        )
+    }
+
+    fn panel_update(resources: &Resources, action: &str) {
+        let mut time_control = match resources.get_mut::<TimeControl>() {
+            Some(time_control) => time_control,
+            None => return,
+        };
+
+        match action {
+            "pause" => time_control.paused = true,
+            "resume" => time_control.paused = false,
+            "step" => time_control.step(),
+            _ if action.starts_with("scale:") => match action["scale:".len()..].parse::<f32>() {
+                Ok(scale) if scale >= 0.0 => time_control.time_scale = scale,
+                _ => eprintln!("invalid time scale in '{}'", action),
+            },
+            _ => eprintln!("unexpected time control action '{}'", action),
+        }
+    }
+}
+
+/// Adds the [`TimeControl`] resource, the [`TIME_CONTROLLED_STAGE`] and a time control panel to the inspector page.
+///
+/// Only the systems added to the [`TIME_CONTROLLED_STAGE`] are paused, see [`TimeControl`].
+#[derive(Default)]
+pub struct TimeControlPlugin;
+
+impl Plugin for TimeControlPlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        app.init_resource::<TimeControl>()
+            .add_system_to_stage(stage::FIRST, TimeControl::update.system())
+            .add_stage_after(
+                stage::UPDATE,
+                TIME_CONTROLLED_STAGE,
+                SystemStage::parallel().with_run_criteria(TimeControl::run_criteria.system()),
+            );
+
+        Panels::add_panel(
+            app,
+            Panel {
+                name: "time",
+                refresh_interval: None,
+                html: TimeControl::panel_html,
+                update: TimeControl::panel_update,
+            },
+        );
+    }
+}
@@ -0,0 +1,73 @@
+// keeps the color picker and the component inputs of a color in sync, see `AsHtml for Color` in html_impls.rs
+
+const srgbToLinear = c => c <= 0.04045 ? c / 12.92 : Math.pow((c + 0.055) / 1.055, 2.4);
+const linearToSrgb = c => c <= 0.0031308 ? c * 12.92 : 1.055 * Math.pow(c, 1 / 2.4) - 0.055;
+
+function rgbToHsv([r, g, b]) {
+    const max = Math.max(r, g, b);
+    const delta = max - Math.min(r, g, b);
+
+    let hue = 0;
+    if (delta !== 0) {
+        if (max === r) hue = 60 * ((((g - b) / delta) % 6 + 6) % 6);
+        else if (max === g) hue = 60 * ((b - r) / delta + 2);
+        else hue = 60 * ((r - g) / delta + 4);
+    }
+    return [hue, max === 0 ? 0 : delta / max, max];
+}
+
+function hsvToRgb([hue, saturation, value]) {
+    const f = n => {
+        const k = (n + hue / 60) % 6;
+        return value - value * saturation * Math.max(0, Math.min(k, 4 - k, 1));
+    };
+    return [f(5), f(3), f(1)];
+}
+
+function toSrgb(mode, components) {
+    switch (mode) {
+        case "hsv": return hsvToRgb(components);
+        case "linear": return components.map(linearToSrgb);
+        default: return components;
+    }
+}
+
+function fromSrgb(mode, rgb) {
+    switch (mode) {
+        case "hsv": return rgbToHsv(rgb);
+        case "linear": return rgb.map(srgbToLinear);
+        default: return rgb;
+    }
+}
+
+function colorInputs(element) {
+    const container = element.closest(".color");
+    return {
+        mode: container.dataset.colorMode,
+        picker: container.querySelector("input[type=color]"),
+        components: Array.from(container.querySelectorAll("input[type=number]")),
+    };
+}
+
+function colorFromComponents(input) {
+    const { mode, picker, components } = colorInputs(input);
+    const values = components.map(input => parseFloat(input.value) || 0);
+
+    const hex = toSrgb(mode, values.slice(0, 3))
+        .map(c => Math.round(Math.min(Math.max(c, 0), 1) * 255).toString(16).padStart(2, "0"))
+        .join("");
+    picker.value = "#" + hex;
+
+    return mode + ":" + values.join(",");
+}
+
+function colorFromHex(picker) {
+    const { mode, components } = colorInputs(picker);
+    const rgb = [1, 3, 5].map(i => parseInt(picker.value.substr(i, 2), 16) / 255);
+
+    fromSrgb(mode, rgb).forEach((value, i) => {
+        components[i].value = parseFloat(value.toFixed(4));
+    });
+
+    return mode + ":" + components.map(input => input.value).join(",");
+}
@@ -0,0 +1,170 @@
+// canvas editor for `Curve`, see curve.rs
+
+// `time,value,interpolation;...`
+const parseCurve = (value) => value.split(";")
+    .filter(key => key !== "")
+    .map(key => {
+        const [time, value, interpolation] = key.split(",");
+        return { time: parseFloat(time), value: parseFloat(value), interpolation };
+    });
+
+const serializeCurve = (keyframes) => keyframes
+    .map(key => `${parseFloat(key.time.toFixed(4))},${parseFloat(key.value.toFixed(4))},${key.interpolation}`)
+    .join(";");
+
+function sampleCurve(keyframes, time) {
+    const next = keyframes.findIndex(key => key.time > time);
+    if (keyframes.length === 0) return 0;
+    if (next === 0) return keyframes[0].value;
+    if (next === -1) return keyframes[keyframes.length - 1].value;
+
+    const from = keyframes[next - 1], to = keyframes[next];
+    let t = (time - from.time) / (to.time - from.time);
+    switch (from.interpolation) {
+        case "constant": t = 0; break;
+        case "smooth": t = t * t * (3 - 2 * t); break;
+    }
+    return from.value + (to.value - from.value) * t;
+}
+
+function initCurve(container) {
+    const readonly = container.dataset.readonly !== undefined;
+    const [minTime, maxTime, min, max] = container.dataset.curveRange.split(",").map(parseFloat);
+    const canvas = container.querySelector("canvas");
+    const timeInput = container.querySelector(".curve-keyframe input[name=time]");
+    const valueInput = container.querySelector(".curve-keyframe input[name=value]");
+    const interpolation = container.querySelector(".curve-keyframe select");
+    const remove = container.querySelector(".curve-keyframe button");
+    const submit = container.querySelector("input[type=hidden]");
+
+    let keyframes = parseCurve(container.dataset.curve);
+    let selected = keyframes[0];
+    let dragging = false;
+
+    const ctx = canvas.getContext("2d");
+    const radius = 4;
+
+    // from the range of the curve to the canvas and back
+    const canvasX = time => (time - minTime) / (maxTime - minTime) * canvas.width;
+    const canvasY = value => (max - value) / (max - min) * canvas.height;
+    const clamp = (x, lower, upper) => Math.min(Math.max(x, lower), upper);
+
+    const pointFromEvent = (e) => {
+        const rect = canvas.getBoundingClientRect();
+        const x = (e.clientX - rect.left) / rect.width;
+        const y = (e.clientY - rect.top) / rect.height;
+        return {
+            time: clamp(minTime + x * (maxTime - minTime), minTime, maxTime),
+            value: clamp(max - y * (max - min), min, max),
+        };
+    };
+
+    const draw = () => {
+        ctx.clearRect(0, 0, canvas.width, canvas.height);
+
+        ctx.strokeStyle = "#ccc";
+        ctx.beginPath();
+        ctx.moveTo(0, canvasY(0));
+        ctx.lineTo(canvas.width, canvasY(0));
+        ctx.stroke();
+
+        ctx.strokeStyle = "#000";
+        ctx.beginPath();
+        for (let x = 0; x <= canvas.width; x++) {
+            const time = minTime + x / canvas.width * (maxTime - minTime);
+            const y = canvasY(sampleCurve(keyframes, time));
+            if (x === 0) ctx.moveTo(x, y);
+            else ctx.lineTo(x, y);
+        }
+        ctx.stroke();
+
+        for (const key of keyframes) {
+            ctx.fillStyle = key === selected ? "#f00" : "#000";
+            ctx.beginPath();
+            ctx.arc(canvasX(key.time), canvasY(key.value), radius, 0, Math.PI * 2, false);
+            ctx.fill();
+        }
+    };
+
+    const showSelected = () => {
+        if (!selected) return;
+        timeInput.value = parseFloat(selected.time.toFixed(4));
+        valueInput.value = parseFloat(selected.value.toFixed(4));
+        interpolation.value = selected.interpolation;
+    };
+
+    const send = () => {
+        keyframes.sort((a, b) => a.time - b.time);
+        submit.value = serializeCurve(keyframes);
+        submit.dispatchEvent(new Event("input"));
+    };
+
+    const keyframeAt = (e) => {
+        const rect = canvas.getBoundingClientRect();
+        const x = (e.clientX - rect.left) / rect.width * canvas.width;
+        const y = (e.clientY - rect.top) / rect.height * canvas.height;
+        return keyframes.find(key =>
+            Math.hypot(canvasX(key.time) - x, canvasY(key.value) - y) <= radius * 2);
+    };
+
+    const removeKeyframe = (keyframe) => {
+        // an empty curve can't be edited using the canvas anymore
+        if (!keyframe || keyframes.length <= 1) return;
+        keyframes = keyframes.filter(key => key !== keyframe);
+        selected = keyframes[0];
+        showSelected();
+        draw();
+        send();
+    };
+
+    draw();
+    showSelected();
+    if (readonly) return;
+
+    canvas.addEventListener("mousedown", (e) => {
+        selected = keyframeAt(e);
+        if (!selected) {
+            const { time, value } = pointFromEvent(e);
+            selected = { time, value, interpolation: interpolation.value || "linear" };
+            keyframes.push(selected);
+            send();
+        }
+        dragging = true;
+        showSelected();
+        draw();
+    });
+    canvas.addEventListener("mousemove", (e) => {
+        if (!dragging) return;
+        Object.assign(selected, pointFromEvent(e));
+        showSelected();
+        draw();
+        send();
+    });
+    const endDrag = () => dragging = false;
+    canvas.addEventListener("mouseup", endDrag);
+    canvas.addEventListener("mouseout", endDrag);
+    canvas.addEventListener("dblclick", (e) => removeKeyframe(keyframeAt(e)));
+
+    const editSelected = (edit) => () => {
+        if (!selected) return;
+        edit(selected);
+        draw();
+        send();
+    };
+    timeInput.addEventListener("input", editSelected(key => key.time = parseFloat(timeInput.value) || 0));
+    valueInput.addEventListener("input", editSelected(key => key.value = parseFloat(valueInput.value) || 0));
+    interpolation.addEventListener("input", editSelected(key => key.interpolation = interpolation.value));
+    remove.addEventListener("click", () => removeKeyframe(selected));
+}
+
+const initCurves = (root) => {
+    for (const container of root.querySelectorAll(".curve")) {
+        if (container.dataset.curveInitialized !== undefined) continue;
+        container.dataset.curveInitialized = "";
+        initCurve(container);
+    }
+};
+
+initCurves(document);
+// readonly fields and inserted list items are initialized when they're added, see `initInsertedHtml` in script.js
+document.addEventListener("watch-update", (e) => initCurves(e.target));
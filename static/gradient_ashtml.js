@@ -0,0 +1,164 @@
+// canvas editor for `Gradient`, see gradient.rs
+
+// `position,r,g,b,a;...` with the color in sRGB between 0 and 1
+const parseGradient = (value) => value.split(";")
+    .filter(stop => stop !== "")
+    .map(stop => {
+        const [position, ...color] = stop.split(",").map(parseFloat);
+        return { position, color };
+    });
+
+const serializeGradient = (stops) => stops
+    .map(stop => [stop.position, ...stop.color].map(c => parseFloat(c.toFixed(4))).join(","))
+    .join(";");
+
+function sampleGradient(stops, position) {
+    const next = stops.findIndex(stop => stop.position > position);
+    if (next === 0) return stops[0].color;
+    if (next === -1) return stops[stops.length - 1].color;
+
+    const from = stops[next - 1], to = stops[next];
+    const t = (position - from.position) / (to.position - from.position);
+    return from.color.map((c, i) => c + (to.color[i] - c) * t);
+}
+
+const gradientHex = (color) => "#" + color.slice(0, 3)
+    .map(c => Math.round(Math.min(Math.max(c, 0), 1) * 255).toString(16).padStart(2, "0"))
+    .join("");
+
+function initGradient(container) {
+    const readonly = container.dataset.readonly !== undefined;
+    const canvas = container.querySelector("canvas");
+    const picker = container.querySelector(".gradient-stop input[type=color]");
+    const alpha = container.querySelector(".gradient-stop input[type=number]");
+    const remove = container.querySelector(".gradient-stop button");
+    const submit = container.querySelector("input[type=hidden]");
+
+    let stops = parseGradient(container.dataset.gradient);
+    let selected = stops[0];
+    let dragging = false;
+
+    const ctx = canvas.getContext("2d");
+    const markerHeight = 10;
+    const barHeight = canvas.height - markerHeight;
+
+    const draw = () => {
+        ctx.clearRect(0, 0, canvas.width, canvas.height);
+
+        // checkerboard behind transparent colors
+        const tile = barHeight / 4;
+        for (let x = 0; x < canvas.width; x += tile) {
+            for (let y = 0; y < barHeight; y += tile) {
+                ctx.fillStyle = (x + y) / tile % 2 === 0 ? "#ccc" : "#fff";
+                ctx.fillRect(x, y, tile, tile);
+            }
+        }
+
+        const fill = ctx.createLinearGradient(0, 0, canvas.width, 0);
+        for (const stop of stops) {
+            const [r, g, b, a] = stop.color;
+            const position = Math.min(Math.max(stop.position, 0), 1);
+            fill.addColorStop(position, `rgba(${r * 255}, ${g * 255}, ${b * 255}, ${a})`);
+        }
+        ctx.fillStyle = fill;
+        ctx.fillRect(0, 0, canvas.width, barHeight);
+
+        for (const stop of stops) {
+            const x = stop.position * canvas.width;
+            ctx.beginPath();
+            ctx.moveTo(x, barHeight);
+            ctx.lineTo(x - markerHeight / 2, canvas.height);
+            ctx.lineTo(x + markerHeight / 2, canvas.height);
+            ctx.closePath();
+            ctx.fillStyle = gradientHex(stop.color);
+            ctx.fill();
+            ctx.strokeStyle = stop === selected ? "#f00" : "#000";
+            ctx.stroke();
+        }
+    };
+
+    const showSelected = () => {
+        if (!selected) return;
+        picker.value = gradientHex(selected.color);
+        alpha.value = parseFloat(selected.color[3].toFixed(4));
+    };
+
+    const send = () => {
+        stops.sort((a, b) => a.position - b.position);
+        submit.value = serializeGradient(stops);
+        submit.dispatchEvent(new Event("input"));
+    };
+
+    const positionFromEvent = (e) => {
+        const rect = canvas.getBoundingClientRect();
+        const position = (e.clientX - rect.left) / rect.width;
+        return Math.min(Math.max(position, 0), 1);
+    };
+
+    draw();
+    showSelected();
+    if (readonly) return;
+
+    canvas.addEventListener("mousedown", (e) => {
+        const position = positionFromEvent(e);
+        const tolerance = markerHeight / canvas.width;
+
+        const nearest = stops.reduce((nearest, stop) =>
+            Math.abs(stop.position - position) < Math.abs(nearest.position - position) ? stop : nearest, stops[0]);
+
+        if (nearest && Math.abs(nearest.position - position) <= tolerance) {
+            selected = nearest;
+        } else {
+            selected = { position, color: sampleGradient(stops, position) };
+            stops.push(selected);
+            send();
+        }
+        dragging = true;
+        showSelected();
+        draw();
+    });
+    canvas.addEventListener("mousemove", (e) => {
+        if (!dragging) return;
+        selected.position = positionFromEvent(e);
+        draw();
+        send();
+    });
+    const endDrag = () => dragging = false;
+    canvas.addEventListener("mouseup", endDrag);
+    canvas.addEventListener("mouseout", endDrag);
+
+    picker.addEventListener("input", () => {
+        if (!selected) return;
+        const rgb = [1, 3, 5].map(i => parseInt(picker.value.substr(i, 2), 16) / 255);
+        selected.color = [...rgb, selected.color[3]];
+        draw();
+        send();
+    });
+    alpha.addEventListener("input", () => {
+        if (!selected) return;
+        selected.color[3] = Math.min(Math.max(parseFloat(alpha.value) || 0, 0), 1);
+        draw();
+        send();
+    });
+    remove.addEventListener("click", () => {
+        // a gradient without stops has no color to show
+        if (!selected || stops.length <= 1) return;
+        stops = stops.filter(stop => stop !== selected);
+        selected = stops[0];
+        showSelected();
+        draw();
+        send();
+    });
+}
+
+const initGradients = (root) => {
+    for (const container of root.querySelectorAll(".gradient")) {
+        if (container.dataset.gradientInitialized !== undefined) continue;
+        container.dataset.gradientInitialized = "";
+        initGradient(container);
+    }
+};
+
+initGradients(document);
+// readonly fields and inserted list items are initialized when they're added, see `initInsertedHtml` in script.js
+document.addEventListener("watch-update", (e) => initGradients(e.target));
@@ -0,0 +1,147 @@
+// used for the ids of lists and the keys of elements added in the browser,
+// the ones rendered by the app are numbered starting at 0
+let listCounter = 0;
+let draggedListItem = null;
+
+function listItems(list) {
+    return list.querySelector(":scope > .list-items");
+}
+
+// the current index of the element, which changes when elements are reordered or removed
+function listIndex(itemId) {
+    const item = document.getElementById(itemId);
+    return Array.from(item.parentElement.children).indexOf(item);
+}
+
+function numberListItems(list) {
+    Array.from(listItems(list).children).forEach((item, i) => {
+        item.querySelector(":scope > .list-controls > .list-index").textContent = i;
+    });
+}
+
+// clones the template of the list or map with the given id, appends it to its items and returns the new item
+function instantiateListTemplate(listId, key) {
+    const list = document.getElementById(listId);
+    const template = document.getElementById(listId + "_template");
+
+    // nested lists in the template get new ids, so that they don't clash with those of other added elements.
+    // the key is inserted afterwards, since it could look like an id as well
+    const ids = {};
+    const html = template.innerHTML
+        .replace(/list_c?\d+/g, id => id === listId ? id : (ids[id] = ids[id] || "list_c" + listCounter++))
+        .split(`__key_${listId}__`).join(key);
+
+    const container = document.createElement("template");
+    container.innerHTML = html;
+    const item = container.content.firstElementChild;
+    listItems(list).appendChild(item);
+
+    initInsertedHtml(item);
+
+    return item;
+}
+
+function addListItem(listId) {
+    instantiateListTemplate(listId, "c" + listCounter++);
+    numberListItems(document.getElementById(listId));
+    return "push";
+}
+
+function removeListItem(button) {
+    const item = button.closest(".list-item");
+    const list = item.closest(".list");
+    const index = listIndex(item.id);
+
+    item.remove();
+    numberListItems(list);
+    return "remove:" + index;
+}
+
+function startListDrag(event) {
+    const handle = event.target.closest(".list-handle");
+    if (!handle) return;
+
+    // only the innermost list handles the drag
+    event.stopPropagation();
+    event.dataTransfer.setData("text/plain", "");
+    draggedListItem = handle.closest(".list-item");
+}
+
+function listDragOver(event, items) {
+    if (draggedListItem && draggedListItem.parentElement === items) {
+        event.preventDefault();
+        event.stopPropagation();
+    }
+}
+
+// moves the dragged element in front of or behind the one it was dropped on and returns the update to submit
+function dropListItem(event, items) {
+    const dragged = draggedListItem;
+    draggedListItem = null;
+    if (!dragged || dragged.parentElement !== items) return;
+    event.preventDefault();
+    event.stopPropagation();
+
+    let target = event.target.closest(".list-item");
+    while (target && target.parentElement !== items) {
+        target = target.parentElement.closest(".list-item");
+    }
+    if (!target || target === dragged) return;
+
+    const from = listIndex(dragged.id);
+    const to = listIndex(target.id);
+    if (from < to) {
+        target.after(dragged);
+    } else {
+        target.before(dragged);
+    }
+
+    numberListItems(items.closest(".list"));
+    return `move:${from}:${to}`;
+}
+
+// like `encodeURIComponent`, but also escapes `'` so that the key can be used in the submit functions,
+// see `encode_key` in containers.rs
+function encodeMapKey(key) {
+    return encodeURIComponent(key).replace(/'/g, "%27");
+}
+
+// submits the key of a new entry and only adds it once the server accepted it,
+// since it may reject keys which don't parse or aren't in the form they are displayed in
+function addMapEntry(mapId, button, submit) {
+    const input = button.parentElement.querySelector("input");
+    const key = encodeMapKey(input.value);
+    if (!input.value) return;
+    // see `key_pattern` in containers.rs
+    if (!checkPattern(input)) {
+        input.reportValidity();
+        return;
+    }
+
+    const map = document.getElementById(mapId);
+    if (listItems(map).querySelector(`:scope > [data-key="${key}"]`)) {
+        alert(`'${input.value}' already exists`);
+        return;
+    }
+
+    Promise.resolve(submit("insert:" + key)).then(response => {
+        // the update was dropped by a later one, see `throttle` in script.js
+        if (!response) return;
+        if (!response.ok) {
+            return response.text().then(error => {
+                input.setCustomValidity(error || `'${input.value}' can't be inserted`);
+                input.reportValidity();
+            });
+        }
+
+        const item = instantiateListTemplate(mapId, key);
+        item.querySelector(":scope > .list-controls > .map-key").textContent = decodeURIComponent(key);
+        if (encodeMapKey(input.value) === key) input.value = "";
+    });
+}
+
+function removeMapEntry(button) {
+    const item = button.closest(".list-item");
+    item.remove();
+    return "remove:" + item.dataset.key;
+}
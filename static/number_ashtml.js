@@ -0,0 +1,216 @@
+// turns number inputs with `data-numscrubber` into inputs which can be scrubbed by dragging horizontally,
+// see `impl_ashtml_for_int!` in html_impls.rs
+//
+// `data-numscrubber="range"`: the scrubbing is done by an invisible range input on top of the number input.
+// For non-linear scales the range goes from 0 to 1 and is mapped to the value of the number input.
+//
+// `data-numscrubber="drag"`: dragging changes the value by `step` per pixel (times 10 with shift, 0.1 with alt),
+// clicking focuses the input so that any value can be typed in.
+// Dragging is limited to ±2^53, beyond which not every integer can be represented, larger values have to be typed in.
+
+function numberScale(input) {
+    const min = parseFloat(input.min), max = parseFloat(input.max);
+    const scale = input.dataset.scale || "linear";
+
+    const pow = scale.match(/^pow\((.+)\)$/);
+    if (pow) {
+        const exponent = parseFloat(pow[1]);
+        return {
+            toSlider: value => Math.pow((value - min) / (max - min), 1 / exponent),
+            fromSlider: position => min + (max - min) * Math.pow(position, exponent),
+        };
+    }
+    if (scale === "log") {
+        const logMin = Math.log(min), logMax = Math.log(max);
+        return {
+            toSlider: value => (Math.log(value) - logMin) / (logMax - logMin),
+            fromSlider: position => Math.exp(logMin + (logMax - logMin) * position),
+        };
+    }
+    return null;
+}
+
+// values from non-linear scales are rounded to 4 significant digits, or to the step if it's a whole number
+function roundScaled(value, step) {
+    const rounded = parseFloat(value.toPrecision(4));
+    return Number.isInteger(step) && step >= 1 ? Math.round(rounded / step) * step : rounded;
+}
+
+const stepDecimals = (step) => (step.toString().split(".")[1] || "").length;
+
+function initDragScrubber(input) {
+    const baseStep = parseFloat(input.step) || 1;
+    const min = Math.max(input.min === "" ? -Infinity : parseFloat(input.min), -Number.MAX_SAFE_INTEGER);
+    const max = Math.min(input.max === "" ? Infinity : parseFloat(input.max), Number.MAX_SAFE_INTEGER);
+
+    const idleCursor = () => input.style.cursor = input.disabled ? "" : "e-resize";
+    idleCursor();
+    input.addEventListener("focus", () => input.style.cursor = "text");
+    input.addEventListener("blur", idleCursor);
+
+    let drag = null;
+    input.addEventListener("pointerdown", (e) => {
+        if (input.disabled || document.activeElement === input) return;
+        e.preventDefault();
+        const value = parseFloat(input.value) || 0;
+        // values beyond ±2^53 aren't dragged, since that would round them. Clicking still focuses them
+        const outOfRange = Math.abs(value) > Number.MAX_SAFE_INTEGER;
+        drag = { x: e.clientX, value, dragged: false, outOfRange };
+        input.setPointerCapture(e.pointerId);
+    });
+    input.addEventListener("pointermove", (e) => {
+        if (!drag || drag.outOfRange) return;
+        const dx = e.clientX - drag.x;
+        if (!drag.dragged && Math.abs(dx) < 3) return;
+        drag.dragged = true;
+        drag.x = e.clientX;
+
+        let step = baseStep * (e.shiftKey ? 10 : 1) * (e.altKey ? 0.1 : 1);
+        // integers stay integers
+        if (Number.isInteger(baseStep)) step = Math.max(1, Math.round(step));
+
+        drag.value = Math.min(Math.max(drag.value + dx * step, min), max);
+        input.value = parseFloat(drag.value.toFixed(stepDecimals(step)));
+        input.dispatchEvent(new Event("input"));
+    });
+    input.addEventListener("pointerup", () => {
+        if (drag && !drag.dragged) {
+            input.focus();
+            input.select();
+        }
+        drag = null;
+    });
+}
+
+function initNumscrubber(input) {
+    const scale = numberScale(input);
+
+    input.readOnly = true;
+    input.setAttribute("style", "-moz-appearance: textfield");
+
+    const wrapper = document.createElement("span");
+    input.parentElement.replaceChild(wrapper, input);
+    wrapper.style.position = "relative";
+    wrapper.appendChild(input);
+    wrapper.style.width = input.offsetWidth + "px";
+    wrapper.style.height = input.offsetHeight + "px";
+
+    const range = document.createElement("input");
+    range.type = "range";
+    wrapper.appendChild(range);
+    if (input.disabled) range.disabled = true;
+
+    if (scale) {
+        range.min = 0;
+        range.max = 1;
+        range.step = "any";
+        range.value = scale.toSlider(parseFloat(input.value));
+    } else {
+        range.step = input.step;
+        range.min = input.min;
+        range.max = input.max;
+        range.value = input.value;
+    }
+
+    const style = input.currentStyle || window.getComputedStyle(input);
+    Object.assign(range.style, {
+        position: "absolute",
+        margin: style.margin,
+        left: 0,
+        border: "1px solid transparent",
+        opacity: 0,
+        cursor: "e-resize",
+        width: input.offsetWidth + "px",
+        height: input.offsetHeight + "px",
+    });
+
+    range.addEventListener("input", () => {
+        input.value = scale
+            ? roundScaled(scale.fromSlider(parseFloat(range.value)), parseFloat(input.step))
+            : range.value;
+        input.dispatchEvent(new Event("input"));
+    });
+}
+
+const initNumscrubbers = (root) => {
+    for (const input of root.querySelectorAll("input[type=number][data-numscrubber]")) {
+        if (input.dataset.numscrubberInitialized !== undefined) continue;
+        input.dataset.numscrubberInitialized = "";
+        if (input.dataset.numscrubber === "drag") initDragScrubber(input);
+        else initNumscrubber(input);
+    }
+};
+
+// readonly fields and inserted list items are initialized when they're added, see `initInsertedHtml` in script.js
+document.addEventListener("watch-update", (e) => initNumscrubbers(e.target));
+
+// angles are displayed in degrees, wrapping around after a full turn from `data-angle-start`
+
+const wrapDegrees = (degrees, start) => start + (((degrees - start) % 360) + 360) % 360;
+
+function drawAngleDial(canvas, degrees) {
+    const ctx = canvas.getContext("2d");
+    const center = canvas.width / 2, radius = canvas.width / 2 - 2;
+    const radians = degrees * Math.PI / 180;
+
+    ctx.clearRect(0, 0, canvas.width, canvas.height);
+    ctx.lineWidth = 2;
+    ctx.beginPath();
+    ctx.arc(center, center, radius, 0, Math.PI * 2);
+    ctx.stroke();
+
+    // counter-clockwise from the right, like the unit circle
+    ctx.beginPath();
+    ctx.moveTo(center, center);
+    ctx.lineTo(center + Math.cos(radians) * radius, center - Math.sin(radians) * radius);
+    ctx.stroke();
+}
+
+// the value of the angle `input` belongs to in its stored unit, see `angle_html` in html_impls.rs
+const angleValue = (input) => {
+    const angle = input.closest(".angle");
+    const degrees = wrapDegrees(parseFloat(input.value) || 0, parseFloat(angle.dataset.angleStart));
+    drawAngleDial(angle.querySelector("canvas"), degrees);
+
+    return angle.dataset.angleUnit === "degrees" ? degrees : degrees * Math.PI / 180;
+};
+
+// dragging the dial sets the angle in whole degrees, or in steps of 15 degrees with shift
+function initAngleDial(angle) {
+    const canvas = angle.querySelector("canvas");
+    const input = angle.querySelector("input");
+    const start = parseFloat(angle.dataset.angleStart);
+    drawAngleDial(canvas, parseFloat(input.value) || 0);
+    if (input.disabled) return;
+
+    const setFromPointer = (e) => {
+        const rect = canvas.getBoundingClientRect();
+        const x = e.clientX - rect.left - rect.width / 2;
+        const y = rect.top + rect.height / 2 - e.clientY;
+        const snap = e.shiftKey ? 15 : 1;
+
+        const degrees = Math.round(Math.atan2(y, x) * 180 / Math.PI / snap) * snap;
+        input.value = wrapDegrees(degrees, start);
+        input.dispatchEvent(new Event("input"));
+    };
+
+    let dragging = false;
+    canvas.style.cursor = "pointer";
+    canvas.addEventListener("pointerdown", (e) => {
+        dragging = true;
+        canvas.setPointerCapture(e.pointerId);
+        setFromPointer(e);
+    });
+    canvas.addEventListener("pointermove", (e) => dragging && setFromPointer(e));
+    canvas.addEventListener("pointerup", () => dragging = false);
+}
+
+const initAngleDials = (root) => {
+    for (const angle of root.querySelectorAll(".angle")) {
+        if (angle.dataset.angleInitialized !== undefined) continue;
+        angle.dataset.angleInitialized = "";
+        initAngleDial(angle);
+    }
+};
+
+document.addEventListener("watch-update", (e) => initAngleDials(e.target));
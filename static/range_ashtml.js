@@ -0,0 +1,30 @@
+// keeps the two sliders of a bounded range in sync with its number inputs, see `range_html` in html_impls/range.rs.
+// The handles can't be dragged past each other.
+
+function initRangeSlider(container) {
+    const numbers = [container.querySelector(".range-start"), container.querySelector(".range-end")];
+    const sliders = container.querySelectorAll(".range-track input");
+    if (sliders.length !== 2) return;
+
+    sliders.forEach((slider, i) => {
+        slider.addEventListener("input", () => {
+            const other = parseFloat(sliders[1 - i].value);
+            const value = parseFloat(slider.value);
+            if (i === 0 ? value > other : value < other) slider.value = other;
+
+            numbers[i].value = slider.value;
+            numbers[i].dispatchEvent(new Event("input"));
+        });
+        numbers[i].addEventListener("input", () => slider.value = numbers[i].value);
+    });
+}
+
+const initRangeSliders = (root) => {
+    for (const container of root.querySelectorAll(".range-slider")) {
+        if (container.dataset.rangeInitialized !== undefined) continue;
+        container.dataset.rangeInitialized = "";
+        initRangeSlider(container);
+    }
+};
+
+document.addEventListener("watch-update", (e) => initRangeSliders(e.target));
@@ -0,0 +1,213 @@
+// calls `func` at most every `limit` milliseconds, dropping all but the last call in between.
+// Returns a promise of the result of `func`, or of `undefined` if the call was dropped.
+const throttle = (func, limit) => {
+    if (!limit) return (...args) => Promise.resolve(func(...args));
+
+    let lastFunc, lastRan, dropLast;
+    return function () {
+        const context = this, args = arguments;
+        if (!lastRan) {
+            lastRan = Date.now();
+            return Promise.resolve(func.apply(context, args));
+        }
+
+        clearTimeout(lastFunc);
+        if (dropLast) dropLast();
+        return new Promise(resolve => {
+            dropLast = () => resolve(undefined);
+            lastFunc = setTimeout(function () {
+                dropLast = undefined;
+                if ((Date.now() - lastRan) >= limit) {
+                    resolve(func.apply(context, args));
+                    lastRan = Date.now();
+                } else {
+                    resolve(undefined);
+                }
+            }, limit - (Date.now() - lastRan));
+        });
+    }
+}
+
+// submits an update, resolving to the response once the app has applied it
+const handleChange = throttle((field, data) => {
+    let body = field + ':' + data;
+    return fetch("", { method: "PUT", body })
+        .then(response => {
+            if (!response.ok) response.clone().text().then(error => console.error(error || response.status));
+            return response;
+        })
+        .catch(e => {
+            console.error(e);
+            alert(e);
+        })
+}, handleChangeThrottle); // set in page.rs
+
+const refreshPanel = (name) => fetch("/panels/" + name)
+    .then(response => response.text())
+    .then(html => document.getElementById("panel-" + name).innerHTML = html)
+    .catch(e => console.error(e));
+
+const panelAction = (name, action) => fetch("/panels/" + name, { method: "PUT", body: action })
+    .then(() => refreshPanel(name))
+    .catch(e => {
+        console.error(e);
+        alert(e);
+    });
+
+// replaces the `#[inspectable(readonly)]` fields with their current values, see `Inspectable::watched`.
+// Of the other fields, only the parts marked with `data-live` are replaced, see `LiveParts` in html_impls.rs
+const startWatching = (interval) => setInterval(() => fetch("/watch")
+    .then(response => response.text())
+    .then(html => {
+        const templates = document.createElement("div");
+        templates.innerHTML = html;
+
+        for (const template of templates.getElementsByTagName("template")) {
+            const container = document.querySelector(`[data-watch="${template.dataset.field}"]`);
+            if (!container) continue;
+
+            if (container.dataset.watchLive === undefined) {
+                container.innerHTML = template.innerHTML;
+            } else {
+                // the parts don't match while elements added in the browser haven't been rendered by the app yet
+                const parts = container.querySelectorAll("[data-live]");
+                const current = template.content.querySelectorAll("[data-live]");
+                if (parts.length !== current.length) continue;
+                parts.forEach((part, i) => part.innerHTML = current[i].innerHTML);
+            }
+            container.dispatchEvent(new CustomEvent("watch-update", { bubbles: true }));
+        }
+    })
+    .catch(e => console.error(e)), interval);
+
+// the comma separated components of the vector `input` belongs to, see `vector_html` in html_impls.rs
+const vectorValue = (input) => {
+    const vector = input.closest(".vector");
+    const inputs = Array.from(vector.querySelectorAll("input[type=number]"));
+
+    const lock = vector.querySelector(".vector-lock");
+    if (lock && lock.checked) {
+        for (const other of inputs) other.value = input.value;
+    }
+
+    return inputs.map(input => input.value).join(",");
+};
+
+// whether the text of `input` matches its `data-pattern`, marking it as invalid if it doesn't.
+// Like the `pattern` attribute, the whole text has to match. See `AsHtml for String` in html_impls.rs
+const checkPattern = (input) => {
+    if (input.dataset.pattern === undefined) {
+        input.setCustomValidity("");
+        return true;
+    }
+
+    const valid = new RegExp(`^(?:${input.dataset.pattern})$`, "u").test(input.value);
+    input.setCustomValidity(valid ? "" : `doesn't match the pattern ${input.dataset.pattern}`);
+    return valid;
+};
+
+// fills the suggestions of a path picker with the files in the asset folder, see `path_html` in html_impls/path.rs
+const loadAssetFiles = (input) => {
+    const picker = input.closest(".path-picker");
+    if (picker.dataset.loaded !== undefined) return;
+    picker.dataset.loaded = "";
+
+    const extensions = picker.dataset.extensions;
+    fetch("/files" + (extensions ? "?extensions=" + encodeURIComponent(extensions) : ""))
+        .then(response => response.text())
+        .then(html => picker.querySelector("datalist").innerHTML = html)
+        .catch(e => {
+            delete picker.dataset.loaded;
+            console.error(e);
+        });
+};
+
+// the loaded assets of each type used in a `Handle<T>` field as `<option>`s, see handles.rs
+const handleOptions = {};
+
+// fills the select of a handle with the loaded assets of its type, `refresh` fetches them again
+const loadHandles = (select, refresh) => {
+    const type = select.dataset.handleType;
+    if (refresh || !handleOptions[type]) {
+        handleOptions[type] = fetch("/handles/" + encodeURIComponent(type))
+            .then(response => response.ok ? response.text() : Promise.reject(response.status));
+    }
+
+    handleOptions[type]
+        .then(html => {
+            const current = select.selectedOptions[0];
+            select.innerHTML = html;
+            // e.g. the default handle, which doesn't point to a loaded asset
+            if (current && !select.querySelector(`option[value="${current.value}"]`)) select.prepend(current);
+            if (current) select.value = current.value;
+        })
+        .catch(e => {
+            delete handleOptions[type];
+            console.error(e);
+        });
+};
+
+const initHandleSelects = (root) => {
+    for (const select of root.querySelectorAll("select.handle")) {
+        if (select.dataset.handleInitialized !== undefined) continue;
+        select.dataset.handleInitialized = "";
+        loadHandles(select, false);
+    }
+};
+
+document.addEventListener("watch-update", (e) => initHandleSelects(e.target));
+
+// runs the scripts of html inserted using innerHTML, which doesn't do that by itself,
+// and lets widgets like the vec2 canvas initialize themselves
+const initInsertedHtml = (element) => {
+    for (const script of element.querySelectorAll("script")) {
+        const copy = document.createElement("script");
+        copy.textContent = script.textContent;
+        script.replaceWith(copy);
+    }
+    element.dispatchEvent(new CustomEvent("watch-update", { bubbles: true }));
+};
+
+// shows the fields of the selected variant of an enum with their default values, returning what should be submitted
+const selectEnumVariant = (input) => {
+    const container = input.closest(".enum");
+    for (const variant of container.querySelectorAll(":scope > .enum-variant")) {
+        if (variant.dataset.variant === input.value) {
+            const template = container.querySelector(`:scope > template[data-variant="${input.value}"]`);
+            variant.innerHTML = template.innerHTML;
+            variant.style.display = "";
+            initInsertedHtml(variant);
+        } else {
+            variant.innerHTML = "";
+            variant.style.display = "none";
+        }
+    }
+    return input.value;
+};
+
+// shows or hides the value of an `Option`, returning what should be submitted.
+// Switching to `Some` inserts the default value on the server, so the widget is reset to it.
+const toggleOption = (checkbox) => {
+    const option = checkbox.closest(".option");
+    const value = option.querySelector(":scope > .option-value");
+    if (checkbox.checked) {
+        value.innerHTML = option.querySelector(":scope > template.option-default").innerHTML;
+        initInsertedHtml(value);
+    }
+    value.style.display = checkbox.checked ? "" : "none";
+    return checkbox.checked ? "some" : "none";
+};
+
+// the panels are rendered by the app, see panels.rs
+document.addEventListener("DOMContentLoaded", () => fetch("/panels")
+    .then(response => response.ok ? response.text() : "")
+    .then(html => {
+        const panels = document.getElementById("panels");
+        panels.innerHTML = html;
+
+        for (const panel of panels.children) {
+            const interval = parseInt(panel.dataset.refresh);
+            if (interval > 0) setInterval(() => refreshPanel(panel.dataset.name), interval);
+        }
+    })
+    .catch(e => console.error(e)));
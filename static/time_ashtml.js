@@ -0,0 +1,29 @@
+// the duration inputs of durations and timers, see html_impls/time.rs
+
+const durationUnits = { ms: 0.001, s: 1, min: 60 };
+
+// `<number><unit>` of the duration `element` belongs to
+const durationValue = (element) => {
+    const duration = element.closest(".duration");
+    const input = duration.querySelector("input[type=number]");
+    return (parseFloat(input.value) || 0) + duration.dataset.unit;
+};
+
+// converts the displayed number to the selected unit without changing the duration
+const changeDurationUnit = (select) => {
+    const duration = select.closest(".duration");
+    const input = duration.querySelector("input[type=number]");
+    const seconds = (parseFloat(input.value) || 0) * durationUnits[duration.dataset.unit];
+
+    input.value = parseFloat((seconds / durationUnits[select.value]).toFixed(4));
+    duration.dataset.unit = select.value;
+};
+
+// pauses or resumes the timer of `button`, returning what should be submitted.
+// The label is refreshed with the state of the timer in the game, see `timer_live_parts` in html_impls/time.rs
+const toggleTimer = (button) => {
+    const label = button.querySelector("[data-live]");
+    const resume = label.textContent === "Resume";
+    label.textContent = resume ? "Pause" : "Resume";
+    return resume ? "unpause" : "pause";
+};
@@ -25,9 +25,9 @@ const ticks = (start, end, step, f) => {
     }
 };
 
-for (const canvas of document.getElementsByTagName("canvas")) {
-    if (canvas.dataset.vec2d === undefined) continue;
+function initVec2d(canvas) {
     const defaultValue = parsePoint(canvas.dataset.vec2dDefault);
+    const readonly = canvas.dataset.vec2dReadonly !== undefined;
 
     let min = parsePoint(canvas.dataset.vec2dMin);
     let max = parsePoint(canvas.dataset.vec2dMax);
@@ -227,13 +227,13 @@ for (const canvas of document.getElementsByTagName("canvas")) {
     canvas.addEventListener("mousemove", (e) => {
         if (isDragging) {
             handleDrag(e);
-        } else if (e.buttons === 1) {
+        } else if (e.buttons === 1 && !readonly) {
             handlePointSelection(e);
         }
     });
     canvas.addEventListener("mousedown", (e) => {
         if (e.shiftKey) beginDrag(e);
-        else handlePointSelection(e);
+        else if (!readonly) handlePointSelection(e);
     });
 
     canvas.addEventListener("mouseup", endDrag);
@@ -241,3 +241,15 @@ for (const canvas of document.getElementsByTagName("canvas")) {
 
     // canvas.addEventListener("wheel", zoom);
 }
+
+const initVec2dCanvases = (root) => {
+    for (const canvas of root.getElementsByTagName("canvas")) {
+        if (canvas.dataset.vec2d === undefined || canvas.dataset.vec2dInitialized !== undefined) continue;
+        canvas.dataset.vec2dInitialized = "";
+        initVec2d(canvas);
+    }
+};
+
+initVec2dCanvases(document);
+// readonly fields are replaced with their current value, see `startWatching` in script.js
+document.addEventListener("watch-update", (e) => initVec2dCanvases(e.target));